@@ -0,0 +1,127 @@
+// cuboids
+// axis-aligned boxes of integer cells, both corners included, like the bricks of 2023 day 22
+// a brick from 1,0,1 to 1,2,1 is a cuboid of 3 cells
+
+use crate::point3::{Point3, Vector3, DOWN_3D};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Cuboid {
+    pub min: Point3,
+    pub max: Point3,
+}
+
+impl Cuboid {
+    // any two opposite corners, in any order
+    #[inline]
+    pub fn new(a: Point3, b: Point3) -> Self {
+        Cuboid {
+            min: a.min(&b),
+            max: a.max(&b),
+        }
+    }
+
+    // number of cells along each axis
+    #[inline]
+    pub fn size(&self) -> Vector3 {
+        self.max - self.min + Vector3::new(1, 1, 1)
+    }
+
+    #[inline]
+    pub fn volume(&self) -> i64 {
+        let size = self.size();
+        size.x * size.y * size.z
+    }
+
+    #[inline]
+    pub fn contains(&self, point: Point3) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x)
+            && (self.min.y..=self.max.y).contains(&point.y)
+            && (self.min.z..=self.max.z).contains(&point.z)
+    }
+
+    // the cells in both cuboids, if any
+    #[inline]
+    pub fn intersection(&self, other: &Cuboid) -> Option<Cuboid> {
+        let min = self.min.max(&other.min);
+        let max = self.max.min(&other.max);
+        if min.x <= max.x && min.y <= max.y && min.z <= max.z {
+            Some(Cuboid { min, max })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn overlaps(&self, other: &Cuboid) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    #[inline]
+    pub fn translate(&self, vector: Vector3) -> Cuboid {
+        Cuboid {
+            min: self.min + vector,
+            max: self.max + vector,
+        }
+    }
+
+    // move down along z
+    #[inline]
+    pub fn drop(&self, distance: i64) -> Cuboid {
+        self.translate(DOWN_3D * distance)
+    }
+
+    // every cell, x first, then y, then z
+    #[inline]
+    pub fn points(&self) -> impl Iterator<Item = Point3> {
+        let (min, max) = (self.min, self.max);
+        (min.z..=max.z).flat_map(move |z| {
+            (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point3::new(x, y, z)))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point3::UP_3D;
+
+    #[test]
+    pub fn test_cuboid_volume() {
+        let brick = Cuboid::new(Point3::new(1, 2, 1), Point3::new(1, 0, 1));
+        assert_eq!(brick.min, Point3::new(1, 0, 1));
+        assert_eq!(brick.max, Point3::new(1, 2, 1));
+        assert_eq!(brick.size(), Vector3::new(1, 3, 1));
+        assert_eq!(brick.volume(), 3);
+        assert_eq!(brick.points().count(), 3);
+        assert!(brick.contains(Point3::new(1, 1, 1)));
+        assert!(!brick.contains(Point3::new(0, 1, 1)));
+    }
+
+    #[test]
+    pub fn test_cuboid_intersection() {
+        let a = Cuboid::new(Point3::new(0, 0, 0), Point3::new(3, 3, 3));
+        let b = Cuboid::new(Point3::new(2, 1, 3), Point3::new(5, 2, 7));
+        assert_eq!(
+            a.intersection(&b),
+            Some(Cuboid::new(Point3::new(2, 1, 3), Point3::new(3, 2, 3)))
+        );
+        assert!(a.overlaps(&b));
+        assert!(b.overlaps(&a));
+
+        // touching faces do not overlap
+        let c = a.translate(UP_3D * 4);
+        assert_eq!(a.intersection(&c), None);
+        assert!(!a.overlaps(&c));
+        assert!(a.overlaps(&c.drop(1)));
+    }
+
+    #[test]
+    pub fn test_cuboid_points() {
+        let cuboid = Cuboid::new(Point3::new(0, 0, 0), Point3::new(1, 1, 1));
+        let points = cuboid.points().collect::<Vec<_>>();
+        assert_eq!(points.len(), 8);
+        assert_eq!(points[0], Point3::new(0, 0, 0));
+        assert_eq!(points[1], Point3::new(1, 0, 0));
+        assert_eq!(points[7], Point3::new(1, 1, 1));
+    }
+}
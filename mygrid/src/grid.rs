@@ -1,9 +1,50 @@
+use std::convert::TryFrom;
+use std::fmt;
 use std::hash::Hash;
 use std::ops::{Index, IndexMut};
 
 use crate::point::Point;
 
-#[derive(Clone, PartialEq, Eq, Hash)]
+// why an input could not be parsed into a grid
+// lines and columns start at 0, like points
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GridParseError {
+    Empty,
+    // a line does not have as many cells as the first one
+    RaggedLine {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    // a character that does not convert into a cell
+    InvalidCell {
+        line: usize,
+        column: usize,
+        value: char,
+    },
+}
+
+impl fmt::Display for GridParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridParseError::Empty => write!(f, "empty grid"),
+            GridParseError::RaggedLine {
+                line,
+                expected,
+                found,
+            } => write!(f, "line {line}: expected {expected} cells, found {found}"),
+            GridParseError::InvalidCell {
+                line,
+                column,
+                value,
+            } => write!(f, "line {line}, column {column}: invalid cell {value:?}"),
+        }
+    }
+}
+
+impl std::error::Error for GridParseError {}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Grid<T> {
     pub width: usize,
     pub height: usize,
@@ -24,10 +65,7 @@ impl<T> Grid<T> {
     }
 
     #[inline]
-    pub fn new_from_str(input: &str, map_char: &dyn Fn(char) -> T) -> Self
-    where
-        T: From<char>,
-    {
+    pub fn new_from_str(input: &str, map_char: &dyn Fn(char) -> T) -> Self {
         let width = input.lines().next().unwrap().len();
         let content = input
             .chars()
@@ -44,23 +82,24 @@ impl<T> Grid<T> {
         }
     }
 
+    // also returns the position of every cell matching is_start, in reading order
     #[inline]
     pub fn new_from_str_capture_start(
         input: &str,
         map_char: &dyn Fn(char) -> T,
         is_start: &dyn Fn(T) -> bool,
-    ) -> (Self, Point)
+    ) -> (Self, Vec<Point>)
     where
-        T: From<char> + Copy,
+        T: Copy,
     {
         let width = input.lines().next().unwrap().len();
 
         let mut content = Vec::new();
-        let mut start = None;
+        let mut starts = Vec::new();
         for (i, c) in input.chars().filter(|&c| c != '\n').enumerate() {
             let t = map_char(c);
             if is_start(t) {
-                start = Some(Point::new_usize(i / width, i % width));
+                starts.push(Point::new_usize(i / width, i % width));
             }
             content.push(t);
         }
@@ -73,10 +112,64 @@ impl<T> Grid<T> {
                 height,
                 content,
             },
-            start.unwrap(),
+            starts,
         )
     }
 
+    // checked version of `new_from_str`, every character is converted with `TryFrom`
+    // accepts LF and CRLF line endings, trailing empty lines are ignored
+    #[inline]
+    pub fn parse(input: &str) -> Result<Self, GridParseError>
+    where
+        T: TryFrom<char>,
+    {
+        Self::parse_with_markers(input, |_| false).map(|(grid, _)| grid)
+    }
+
+    // same as `parse`, also returns the position of every character matching is_marker,
+    // like the start `S` of a maze, in reading order
+    pub fn parse_with_markers<F>(
+        input: &str,
+        is_marker: F,
+    ) -> Result<(Self, Vec<Point>), GridParseError>
+    where
+        T: TryFrom<char>,
+        F: Fn(char) -> bool,
+    {
+        let input = input.trim_end_matches(['\n', '\r']);
+        let width = input.lines().next().map_or(0, |line| line.chars().count());
+        if width == 0 {
+            return Err(GridParseError::Empty);
+        }
+
+        let mut content = Vec::new();
+        let mut markers = Vec::new();
+        for (line, text) in input.lines().enumerate() {
+            let found = text.chars().count();
+            if found != width {
+                return Err(GridParseError::RaggedLine {
+                    line,
+                    expected: width,
+                    found,
+                });
+            }
+
+            for (column, value) in text.chars().enumerate() {
+                if is_marker(value) {
+                    markers.push(Point::new_usize(line, column));
+                }
+                let t = T::try_from(value).map_err(|_| GridParseError::InvalidCell {
+                    line,
+                    column,
+                    value,
+                })?;
+                content.push(t);
+            }
+        }
+
+        Ok((Self::from_vec(content, width), markers))
+    }
+
     #[inline]
     pub fn from_vec(content: Vec<T>, width: usize) -> Self {
         let height = content.len() / width;
@@ -169,6 +262,13 @@ impl<T> Grid<T> {
             && point.line < (self.height as isize)
     }
 
+    #[inline]
+    pub fn swap(&mut self, a: Point, b: Point) {
+        let a = ((a.line as usize) * self.width) + (a.column as usize);
+        let b = ((b.line as usize) * self.width) + (b.column as usize);
+        self.content.swap(a, b);
+    }
+
     #[inline]
     pub fn iter(&self) -> impl Iterator<Item = &T> {
         self.content.iter()
@@ -239,7 +339,7 @@ impl std::fmt::Display for Grid<String> {
                 let point = Point::new_usize(r, c);
                 write!(f, "{}", self[point])?;
             }
-            writeln!(f, "")?;
+            writeln!(f)?;
         }
         Ok(())
     }
@@ -362,7 +462,81 @@ mod tests {
         assert_eq!(grid.content[6], '7');
         assert_eq!(grid.content[7], '8');
         assert_eq!(grid.content[8], '9');
-        assert_eq!(start, Point::new(1, 1));
+        assert_eq!(start, vec![Point::new(1, 1)]);
+
+        let (_, starts) = Grid::new_from_str_capture_start("S.\n.S", &|c| c, &|c| c == 'S');
+        assert_eq!(starts, vec![Point::new(0, 0), Point::new(1, 1)]);
+        let (_, starts) = Grid::new_from_str_capture_start("..\n..", &|c| c, &|c| c == 'S');
+        assert!(starts.is_empty());
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    enum Tile {
+        Wall,
+        Open,
+    }
+
+    impl TryFrom<char> for Tile {
+        type Error = char;
+
+        fn try_from(c: char) -> Result<Self, Self::Error> {
+            match c {
+                '#' => Ok(Tile::Wall),
+                '.' | 'S' => Ok(Tile::Open),
+                _ => Err(c),
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_grid_parse() {
+        let grid: Grid<Tile> = Grid::parse("#.#\n..#\n").unwrap();
+        assert_eq!(grid.width, 3);
+        assert_eq!(grid.height, 2);
+        assert_eq!(grid[Point::new(0, 0)], Tile::Wall);
+        assert_eq!(grid[Point::new(1, 1)], Tile::Open);
+
+        let crlf: Grid<Tile> = Grid::parse("#.#\r\n..#\r\n\r\n").unwrap();
+        assert_eq!(crlf, grid);
+
+        // any type with a char conversion works, like chars themselves
+        let grid: Grid<char> = Grid::parse("é1\n23").unwrap();
+        assert_eq!(grid.width, 2);
+        assert_eq!(grid[Point::new(0, 0)], 'é');
+    }
+
+    #[test]
+    pub fn test_grid_parse_errors() {
+        assert_eq!(Grid::<Tile>::parse(""), Err(GridParseError::Empty));
+        assert_eq!(Grid::<Tile>::parse("\n\n"), Err(GridParseError::Empty));
+        assert_eq!(
+            Grid::<Tile>::parse("#.#\n..\n###"),
+            Err(GridParseError::RaggedLine {
+                line: 1,
+                expected: 3,
+                found: 2
+            })
+        );
+        let error = Grid::<Tile>::parse("#.#\n.x#\n#y#").unwrap_err();
+        assert_eq!(
+            error,
+            GridParseError::InvalidCell {
+                line: 1,
+                column: 1,
+                value: 'x'
+            }
+        );
+        assert_eq!(error.to_string(), "line 1, column 1: invalid cell 'x'");
+    }
+
+    #[test]
+    pub fn test_grid_parse_with_markers() {
+        let (grid, markers) = Grid::<Tile>::parse_with_markers("S.#\n#.S", |c| c == 'S').unwrap();
+        assert_eq!(grid[Point::new(1, 2)], Tile::Open);
+        assert_eq!(markers, vec![Point::new(0, 0), Point::new(1, 2)]);
+
+        let (_, markers) = Grid::<Tile>::parse_with_markers("..", |c| c == 'S').unwrap();
+        assert!(markers.is_empty());
     }
 
     #[test]
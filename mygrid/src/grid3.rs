@@ -0,0 +1,163 @@
+// 3d grid
+// the dense `Grid` with a third axis, cells are indexed with a `Point3`
+// width goes along x, depth along y and height along z, all starting at 0
+
+use std::ops::{Index, IndexMut};
+
+use crate::cuboid::Cuboid;
+use crate::point3::Point3;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid3<T> {
+    pub width: usize,
+    pub depth: usize,
+    pub height: usize,
+    content: Vec<T>,
+}
+
+impl<T> Grid3<T> {
+    #[inline]
+    pub fn new(width: usize, depth: usize, height: usize, default: T) -> Self
+    where
+        T: Copy,
+    {
+        Self {
+            width,
+            depth,
+            height,
+            content: vec![default; width * depth * height],
+        }
+    }
+
+    // a grid big enough to hold the max point
+    #[inline]
+    pub fn new_to_max_point(point: Point3, default: T) -> Self
+    where
+        T: Copy,
+    {
+        Self::new(
+            (point.x + 1) as usize,
+            (point.y + 1) as usize,
+            (point.z + 1) as usize,
+            default,
+        )
+    }
+
+    #[inline]
+    fn index_of(&self, point: Point3) -> usize {
+        ((point.z as usize) * self.depth + (point.y as usize)) * self.width + (point.x as usize)
+    }
+
+    #[inline]
+    pub fn is_in_bounds(&self, point: Point3) -> bool {
+        point.x >= 0
+            && point.x < (self.width as i64)
+            && point.y >= 0
+            && point.y < (self.depth as i64)
+            && point.z >= 0
+            && point.z < (self.height as i64)
+    }
+
+    #[inline]
+    pub fn get(&self, point: Point3) -> Option<&T> {
+        if self.is_in_bounds(point) {
+            Some(&self[point])
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, point: Point3) -> Option<&mut T> {
+        if self.is_in_bounds(point) {
+            Some(&mut self[point])
+        } else {
+            None
+        }
+    }
+
+    // set every cell of the cuboid, which must be in bounds
+    #[inline]
+    pub fn fill(&mut self, cuboid: &Cuboid, value: T)
+    where
+        T: Copy,
+    {
+        for point in cuboid.points() {
+            self[point] = value;
+        }
+    }
+
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.content.iter()
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.content.iter_mut()
+    }
+
+    #[inline]
+    pub fn iter_item_and_position(&self) -> impl Iterator<Item = (Point3, &T)> {
+        let (width, depth) = (self.width, self.depth);
+        self.content.iter().enumerate().map(move |(i, t)| {
+            let point = Point3::new(
+                (i % width) as i64,
+                ((i / width) % depth) as i64,
+                (i / (width * depth)) as i64,
+            );
+            (point, t)
+        })
+    }
+}
+
+impl<T> Index<Point3> for Grid3<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, point: Point3) -> &Self::Output {
+        &self.content[self.index_of(point)]
+    }
+}
+
+impl<T> IndexMut<Point3> for Grid3<T> {
+    #[inline]
+    fn index_mut(&mut self, point: Point3) -> &mut Self::Output {
+        let index = self.index_of(point);
+        &mut self.content[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_grid3_index() {
+        let mut grid = Grid3::new(2, 3, 4, 0);
+        assert_eq!(grid.iter().count(), 24);
+        grid[Point3::new(1, 2, 3)] = 7;
+        *grid.get_mut(Point3::new(0, 1, 0)).unwrap() = 3;
+        assert_eq!(grid[Point3::new(1, 2, 3)], 7);
+        assert_eq!(grid.get(Point3::new(0, 1, 0)), Some(&3));
+        assert_eq!(grid.get(Point3::new(2, 0, 0)), None);
+        assert_eq!(grid.get(Point3::new(0, 0, -1)), None);
+        assert_eq!(grid.iter().sum::<i32>(), 10);
+
+        let (last, &value) = grid.iter_item_and_position().last().unwrap();
+        assert_eq!((last, value), (Point3::new(1, 2, 3), 7));
+        let (point, _) = grid.iter_item_and_position().nth(3).unwrap();
+        assert_eq!(point, Point3::new(1, 1, 0));
+    }
+
+    #[test]
+    pub fn test_grid3_fill() {
+        let mut grid = Grid3::new_to_max_point(Point3::new(2, 2, 2), false);
+        assert_eq!((grid.width, grid.depth, grid.height), (3, 3, 3));
+        let brick = Cuboid::new(Point3::new(0, 1, 1), Point3::new(2, 1, 1));
+        grid.fill(&brick, true);
+        assert_eq!(grid.iter().filter(|&&c| c).count(), 3);
+        assert!(grid[Point3::new(1, 1, 1)]);
+        assert!(!grid[Point3::new(1, 1, 2)]);
+    }
+}
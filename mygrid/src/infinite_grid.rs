@@ -0,0 +1,156 @@
+// infinite grid
+// a view over a grid that is repeated infinitely in all directions, like the garden of 2023 day 21
+// any point can be used, including negative ones, it is mapped to the real grid with an euclidean modulo
+
+use std::ops::Index;
+
+use crate::direction::Direction;
+use crate::grid::Grid;
+use crate::point::Point;
+
+pub struct InfiniteGrid<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+// derive would require `T: Copy`, only the reference is copied
+impl<T> Clone for InfiniteGrid<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for InfiniteGrid<'_, T> {}
+
+impl<'a, T> InfiniteGrid<'a, T> {
+    #[inline]
+    pub fn new(grid: &'a Grid<T>) -> Self {
+        assert!(
+            grid.width > 0 && grid.height > 0,
+            "cannot tile an empty grid"
+        );
+        Self { grid }
+    }
+
+    #[inline]
+    pub fn grid(&self) -> &'a Grid<T> {
+        self.grid
+    }
+
+    // the cell of the real grid a point maps to
+    #[inline]
+    pub fn to_real(&self, point: Point) -> Point {
+        point.infinite_grid_to_real_grid(self.grid.height as isize, self.grid.width as isize)
+    }
+
+    // the copy of the real grid a point is in, (0, 0) being the real grid itself
+    #[inline]
+    pub fn tile(&self, point: Point) -> Point {
+        point.infinite_grid_tile(self.grid.height as isize, self.grid.width as isize)
+    }
+
+    // whether the point is in the real grid
+    #[inline]
+    pub fn is_in_original(&self, point: Point) -> bool {
+        self.grid.is_in_bounds(point)
+    }
+
+    // whether the point is at most `tiles` copies away from the real grid in each direction
+    #[inline]
+    pub fn is_in_bounds(&self, point: Point, tiles: isize) -> bool {
+        let tile = self.tile(point);
+        tile.line.abs() <= tiles && tile.column.abs() <= tiles
+    }
+
+    #[inline]
+    pub fn get(&self, point: Point) -> &'a T {
+        &self.grid[self.to_real(point)]
+    }
+
+    // the neighbors of a point, they always exist on an infinite grid
+    #[inline]
+    pub fn neighbors<'b>(
+        &self,
+        point: Point,
+        directions: &'b [Direction],
+    ) -> impl Iterator<Item = Point> + 'b {
+        directions.iter().map(move |&direction| point + direction)
+    }
+
+    // the neighbors of a point whose cell matches the predicate, e.g. to skip walls
+    #[inline]
+    pub fn neighbors_where<'b, F>(
+        &self,
+        point: Point,
+        directions: &'b [Direction],
+        predicate: F,
+    ) -> impl Iterator<Item = Point> + 'b
+    where
+        'a: 'b,
+        F: Fn(&T) -> bool + 'b,
+    {
+        let grid = *self;
+        self.neighbors(point, directions)
+            .filter(move |&next| predicate(grid.get(next)))
+    }
+}
+
+impl<T> Index<Point> for InfiniteGrid<'_, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, point: Point) -> &Self::Output {
+        self.get(point)
+    }
+}
+
+impl<'a, T> From<&'a Grid<T>> for InfiniteGrid<'a, T> {
+    #[inline]
+    fn from(grid: &'a Grid<T>) -> Self {
+        Self::new(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::direction::ORTHOGONAL;
+
+    #[test]
+    pub fn test_infinite_grid_index() {
+        let grid = Grid::new_from_str("123\n456", &|c| c);
+        let infinite = InfiniteGrid::new(&grid);
+        assert_eq!(infinite[Point::new(0, 0)], '1');
+        assert_eq!(infinite[Point::new(1, 2)], '6');
+        assert_eq!(infinite[Point::new(2, 3)], '1');
+        assert_eq!(infinite[Point::new(-1, -1)], '6');
+        assert_eq!(infinite[Point::new(-4, 5)], '3');
+        assert_eq!(infinite.to_real(Point::new(-4, 5)), Point::new(0, 2));
+    }
+
+    #[test]
+    pub fn test_infinite_grid_tiles() {
+        let grid = Grid::new_from_str("123\n456", &|c| c);
+        let infinite = InfiniteGrid::from(&grid);
+        assert_eq!(infinite.tile(Point::new(1, 2)), Point::new(0, 0));
+        assert_eq!(infinite.tile(Point::new(-1, 3)), Point::new(-1, 1));
+        assert!(infinite.is_in_original(Point::new(1, 2)));
+        assert!(!infinite.is_in_original(Point::new(2, 2)));
+        assert!(infinite.is_in_bounds(Point::new(-2, 5), 1));
+        assert!(!infinite.is_in_bounds(Point::new(-3, 5), 1));
+    }
+
+    #[test]
+    pub fn test_infinite_grid_neighbors() {
+        let grid = Grid::new_from_str(".#\n..", &|c| c);
+        let infinite = InfiniteGrid::new(&grid);
+        let neighbors = infinite.neighbors(Point::new(0, 0), &ORTHOGONAL).count();
+        assert_eq!(neighbors, 4);
+
+        let open = infinite
+            .neighbors_where(Point::new(0, 0), &ORTHOGONAL, |&c| c != '#')
+            .collect::<Vec<_>>();
+        // the walls at (0, 1) and (0, -1) are copies of the same cell
+        assert_eq!(open, vec![Point::new(-1, 0), Point::new(1, 0)]);
+    }
+}
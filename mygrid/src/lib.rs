@@ -3,6 +3,15 @@
 // heavily inspired by the amazing maneatingape repo, from which I learned a lot, plz see:
 // https://github.com/maneatingape/advent-of-code-rust/blob/main/src/util/point.rs
 
+pub mod cuboid;
 pub mod direction;
 pub mod grid;
+pub mod grid3;
+pub mod infinite_grid;
 pub mod point;
+pub mod point3;
+pub mod polygon;
+pub mod region;
+pub mod search;
+pub mod sparse_grid;
+pub mod view;
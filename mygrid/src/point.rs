@@ -48,6 +48,29 @@ impl Point {
     pub fn min(&self, other: &Point) -> Self {
         Point::new(self.line.min(other.line), self.column.min(other.column))
     }
+
+    // the grid is repeated infinitely in all directions,
+    // map a point of the infinite grid to the same cell of the real grid
+    #[inline]
+    pub fn infinite_grid_to_real_grid(
+        &self,
+        real_grid_lines: isize,
+        real_grid_columns: isize,
+    ) -> Self {
+        Point::new(
+            self.line.rem_euclid(real_grid_lines),
+            self.column.rem_euclid(real_grid_columns),
+        )
+    }
+
+    // which copy of the real grid a point of the infinite grid is in, (0, 0) being the real grid
+    #[inline]
+    pub fn infinite_grid_tile(&self, real_grid_lines: isize, real_grid_columns: isize) -> Self {
+        Point::new(
+            self.line.div_euclid(real_grid_lines),
+            self.column.div_euclid(real_grid_columns),
+        )
+    }
 }
 
 impl Hash for Point {
@@ -119,4 +142,17 @@ mod tests {
         assert_eq!(real_grid_point.line, 1);
         assert_eq!(real_grid_point.column, 0);
     }
+
+    #[test]
+    pub fn test_infinite_grid_tile() {
+        assert_eq!(
+            Point::new(45, 20).infinite_grid_tile(3, 4),
+            Point::new(15, 5)
+        );
+        assert_eq!(
+            Point::new(-5, -8).infinite_grid_tile(3, 4),
+            Point::new(-2, -2)
+        );
+        assert_eq!(Point::new(2, 3).infinite_grid_tile(3, 4), Point::new(0, 0));
+    }
 }
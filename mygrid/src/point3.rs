@@ -0,0 +1,236 @@
+// 3d points and vectors
+// integer coordinates so 3d puzzles don't need floats, z going up like the bricks of 2023 day 22
+// a point is a position, a vector is a move between positions, like `Point` and `Direction` in 2d
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Vector3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+pub const UP_3D: Vector3 = Vector3::new(0, 0, 1);
+pub const DOWN_3D: Vector3 = Vector3::new(0, 0, -1);
+pub const ORTHOGONAL_3D: [Vector3; 6] = [
+    Vector3::new(1, 0, 0),
+    Vector3::new(-1, 0, 0),
+    Vector3::new(0, 1, 0),
+    Vector3::new(0, -1, 0),
+    UP_3D,
+    DOWN_3D,
+];
+
+impl Point3 {
+    #[inline]
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Point3 { x, y, z }
+    }
+
+    #[inline]
+    pub fn min(&self, other: &Point3) -> Self {
+        Point3::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    #[inline]
+    pub fn max(&self, other: &Point3) -> Self {
+        Point3::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+
+    #[inline]
+    pub fn manhattan(&self, other: &Point3) -> i64 {
+        (*other - *self).manhattan()
+    }
+
+    // the 6 points sharing a face with this one
+    #[inline]
+    pub fn neighbors(&self) -> impl Iterator<Item = Point3> {
+        let point = *self;
+        ORTHOGONAL_3D.iter().map(move |&v| point + v)
+    }
+}
+
+impl Vector3 {
+    #[inline]
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Vector3 { x, y, z }
+    }
+
+    #[inline]
+    pub fn manhattan(&self) -> i64 {
+        self.x.abs() + self.y.abs() + self.z.abs()
+    }
+
+    #[inline]
+    pub fn dot(&self, other: &Vector3) -> i64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    #[inline]
+    pub fn cross(&self, other: &Vector3) -> Vector3 {
+        Vector3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+}
+
+impl From<[i64; 3]> for Point3 {
+    #[inline]
+    fn from([x, y, z]: [i64; 3]) -> Self {
+        Point3::new(x, y, z)
+    }
+}
+
+impl From<[i64; 3]> for Vector3 {
+    #[inline]
+    fn from([x, y, z]: [i64; 3]) -> Self {
+        Vector3::new(x, y, z)
+    }
+}
+
+impl Add<Vector3> for Point3 {
+    type Output = Point3;
+
+    #[inline]
+    fn add(self, rhs: Vector3) -> Self::Output {
+        Point3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl AddAssign<Vector3> for Point3 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Vector3) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Vector3> for Point3 {
+    type Output = Point3;
+
+    #[inline]
+    fn sub(self, rhs: Vector3) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl SubAssign<Vector3> for Point3 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Vector3) {
+        *self = *self - rhs;
+    }
+}
+
+// the vector going from rhs to self
+impl Sub<Point3> for Point3 {
+    type Output = Vector3;
+
+    #[inline]
+    fn sub(self, rhs: Point3) -> Self::Output {
+        Vector3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl Add<Vector3> for Vector3 {
+    type Output = Vector3;
+
+    #[inline]
+    fn add(self, rhs: Vector3) -> Self::Output {
+        Vector3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl Sub<Vector3> for Vector3 {
+    type Output = Vector3;
+
+    #[inline]
+    fn sub(self, rhs: Vector3) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl Neg for Vector3 {
+    type Output = Vector3;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Vector3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Mul<i64> for Vector3 {
+    type Output = Vector3;
+
+    #[inline]
+    fn mul(self, rhs: i64) -> Self::Output {
+        Vector3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl std::fmt::Display for Point3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(x:{}, y:{}, z:{})", self.x, self.y, self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_point3_arithmetic() {
+        let a = Point3::new(1, 2, 3);
+        let b = Point3::from([4, 0, -1]);
+        let v = b - a;
+        assert_eq!(v, Vector3::new(3, -2, -4));
+        assert_eq!(a + v, b);
+        assert_eq!(b - v, a);
+        assert_eq!(a + DOWN_3D * 3, Point3::new(1, 2, 0));
+        assert_eq!(a.manhattan(&b), 9);
+        assert_eq!(a.min(&b), Point3::new(1, 0, -1));
+        assert_eq!(a.max(&b), Point3::new(4, 2, 3));
+
+        let mut c = a;
+        c += UP_3D;
+        c -= Vector3::new(1, 1, 1);
+        assert_eq!(c, Point3::new(0, 1, 3));
+        assert_eq!(format!("{}", c), "(x:0, y:1, z:3)");
+    }
+
+    #[test]
+    pub fn test_vector3_products() {
+        let x = Vector3::new(1, 0, 0);
+        let y = Vector3::new(0, 1, 0);
+        assert_eq!(x.cross(&y), UP_3D);
+        assert_eq!(y.cross(&x), DOWN_3D);
+        assert_eq!(x.dot(&y), 0);
+        assert_eq!(Vector3::new(1, 2, 3).dot(&Vector3::new(4, -5, 6)), 12);
+        assert_eq!(-Vector3::new(1, -2, 3), Vector3::new(-1, 2, -3));
+    }
+
+    #[test]
+    pub fn test_point3_neighbors() {
+        let neighbors = Point3::new(0, 0, 0).neighbors().collect::<Vec<_>>();
+        assert_eq!(neighbors.len(), 6);
+        assert!(neighbors
+            .iter()
+            .all(|p| p.manhattan(&Point3::default()) == 1));
+    }
+}
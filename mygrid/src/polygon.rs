@@ -0,0 +1,228 @@
+// polygon
+// lattice polygons given by their corners, e.g. the trench of 2023 day 18
+// areas and point counts are exact, computed with the shoelace formula and Pick's theorem
+// see: https://en.wikipedia.org/wiki/Shoelace_formula and https://en.wikipedia.org/wiki/Pick%27s_theorem
+
+use crate::direction::Direction;
+use crate::point::Point;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Polygon {
+    // corners in walking order, the last one connects back to the first
+    pub vertices: Vec<Point>,
+}
+
+impl Polygon {
+    #[inline]
+    pub fn new(vertices: Vec<Point>) -> Self {
+        Polygon { vertices }
+    }
+
+    // walk the moves from start, each move adds a corner
+    // a closed walk ends on start again, that last corner is dropped
+    pub fn from_moves<I>(start: Point, moves: I) -> Self
+    where
+        I: IntoIterator<Item = (Direction, i64)>,
+    {
+        let mut vertices = vec![start];
+        let mut position = start;
+        for (direction, length) in moves {
+            position = position + direction * length as isize;
+            vertices.push(position);
+        }
+        if vertices.len() > 1 && vertices.last() == Some(&start) {
+            vertices.pop();
+        }
+        Polygon { vertices }
+    }
+
+    #[inline]
+    fn edges(&self) -> impl Iterator<Item = (Point, Point)> + '_ {
+        self.vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+            .map(|(&a, &b)| (a, b))
+    }
+
+    // twice the enclosed area, always an integer for lattice polygons
+    pub fn twice_area(&self) -> i128 {
+        self.edges()
+            .map(|(a, b)| a.column as i128 * b.line as i128 - a.line as i128 * b.column as i128)
+            .sum::<i128>()
+            .abs()
+    }
+
+    // the enclosed area, rounded down for polygons with a half-integer area
+    // exact for polygons with axis-aligned edges
+    #[inline]
+    pub fn area(&self) -> i128 {
+        self.twice_area() / 2
+    }
+
+    // number of lattice points on the edges, corners included
+    pub fn boundary_points(&self) -> i128 {
+        self.edges()
+            .map(|(a, b)| {
+                gcd(
+                    (b.line - a.line).unsigned_abs() as i128,
+                    (b.column - a.column).unsigned_abs() as i128,
+                )
+            })
+            .sum()
+    }
+
+    // number of lattice points strictly inside, with Pick's theorem: A = I + B/2 - 1
+    #[inline]
+    pub fn interior_points(&self) -> i128 {
+        (self.twice_area() - self.boundary_points() + 2) / 2
+    }
+
+    // number of lattice points inside or on the edges, e.g. the cells dug out in 2023 day 18
+    #[inline]
+    pub fn lattice_points(&self) -> i128 {
+        self.interior_points() + self.boundary_points()
+    }
+
+    pub fn is_on_boundary(&self, point: Point) -> bool {
+        self.edges().any(|(a, b)| is_on_segment(point, a, b))
+    }
+
+    // whether the point is inside or on an edge
+    pub fn contains(&self, point: Point) -> bool {
+        if self.is_on_boundary(point) {
+            return true;
+        }
+
+        // cast a ray towards increasing columns and count the crossed edges
+        let (line, column) = (point.line as i128, point.column as i128);
+        let mut inside = false;
+        for (a, b) in self.edges() {
+            let (a_line, a_column) = (a.line as i128, a.column as i128);
+            let (b_line, b_column) = (b.line as i128, b.column as i128);
+            if (a_line > line) == (b_line > line) {
+                continue;
+            }
+
+            // the crossing is right of the point, compared without dividing to stay exact
+            let lhs = (column - a_column) * (b_line - a_line);
+            let rhs = (line - a_line) * (b_column - a_column);
+            let is_right = if b_line > a_line {
+                lhs < rhs
+            } else {
+                lhs > rhs
+            };
+            if is_right {
+                inside = !inside;
+            }
+        }
+        inside
+    }
+}
+
+fn is_on_segment(point: Point, a: Point, b: Point) -> bool {
+    let cross = (b.line - a.line) as i128 * (point.column - a.column) as i128
+        - (b.column - a.column) as i128 * (point.line - a.line) as i128;
+    let min = a.min(&b);
+    let max = a.max(&b);
+    cross == 0
+        && (min.line..=max.line).contains(&point.line)
+        && (min.column..=max.column).contains(&point.column)
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::direction::{DOWN, LEFT, RIGHT, UP};
+
+    fn dig_plan() -> Polygon {
+        let moves = [
+            (RIGHT, 6),
+            (DOWN, 5),
+            (LEFT, 2),
+            (DOWN, 2),
+            (RIGHT, 2),
+            (DOWN, 2),
+            (LEFT, 5),
+            (UP, 2),
+            (LEFT, 1),
+            (UP, 2),
+            (RIGHT, 2),
+            (UP, 3),
+            (LEFT, 2),
+            (UP, 2),
+        ];
+        Polygon::from_moves(Point::new(0, 0), moves)
+    }
+
+    #[test]
+    pub fn test_polygon_from_moves() {
+        let polygon = Polygon::from_moves(
+            Point::new(1, 1),
+            [(RIGHT, 2), (DOWN, 3), (LEFT, 2), (UP, 3)],
+        );
+        assert_eq!(
+            polygon.vertices,
+            vec![
+                Point::new(1, 1),
+                Point::new(1, 3),
+                Point::new(4, 3),
+                Point::new(4, 1)
+            ]
+        );
+        assert_eq!(polygon.area(), 6);
+        assert_eq!(polygon.boundary_points(), 10);
+        assert_eq!(polygon.interior_points(), 2);
+        assert_eq!(polygon.lattice_points(), 12);
+    }
+
+    #[test]
+    pub fn test_polygon_dig_plan() {
+        let polygon = dig_plan();
+        assert_eq!(polygon.boundary_points(), 38);
+        assert_eq!(polygon.area(), 42);
+        assert_eq!(polygon.lattice_points(), 62);
+    }
+
+    #[test]
+    pub fn test_polygon_diagonal_edges() {
+        // triangle with a half-integer area
+        let polygon = Polygon::new(vec![Point::new(0, 0), Point::new(0, 3), Point::new(2, 0)]);
+        assert_eq!(polygon.twice_area(), 6);
+        assert_eq!(polygon.boundary_points(), 6);
+        assert_eq!(polygon.interior_points(), 1);
+
+        let polygon = Polygon::new(vec![Point::new(0, 0), Point::new(1, 2), Point::new(0, 3)]);
+        assert_eq!(polygon.twice_area(), 3);
+        assert_eq!(polygon.area(), 1);
+        assert_eq!(polygon.interior_points(), 0);
+    }
+
+    #[test]
+    pub fn test_polygon_contains() {
+        let polygon = dig_plan();
+        assert!(polygon.contains(Point::new(1, 1)));
+        assert!(polygon.contains(Point::new(0, 0)));
+        assert!(polygon.is_on_boundary(Point::new(0, 3)));
+        assert!(!polygon.is_on_boundary(Point::new(1, 1)));
+        // the notch on the left side
+        assert!(!polygon.contains(Point::new(3, 0)));
+        assert!(!polygon.contains(Point::new(4, 1)));
+        assert!(polygon.is_on_boundary(Point::new(6, 0)));
+        assert!(!polygon.contains(Point::new(3, -1)));
+        assert!(!polygon.contains(Point::new(0, 7)));
+
+        let counted = (-1..=10)
+            .flat_map(|line| (-1..=7).map(move |column| Point::new(line, column)))
+            .filter(|&p| polygon.contains(p))
+            .count();
+        assert_eq!(counted as i128, polygon.lattice_points());
+    }
+}
@@ -0,0 +1,208 @@
+// regions
+// flood fill and connected component labelling on grids
+// connectivity is given as a list of directions, `ORTHOGONAL` or `ALL_AROUND`
+
+use crate::direction::{Direction, ORTHOGONAL};
+use crate::grid::Grid;
+use crate::point::Point;
+
+// a connected component found by `label_components`
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Component {
+    pub id: u32,
+    pub size: usize,
+    // bounding box, both corners included
+    pub min: Point,
+    pub max: Point,
+    // number of cell sides that touch another component or the outside of the grid
+    // always counted orthogonally, also for `ALL_AROUND` connectivity
+    pub perimeter: usize,
+}
+
+pub struct Labels {
+    // id of the component of each cell, 0 for cells that don't match the predicate
+    pub ids: Grid<u32>,
+    // components by id, the component with id 1 comes first
+    pub components: Vec<Component>,
+}
+
+impl Labels {
+    #[inline]
+    pub fn component(&self, id: u32) -> Option<&Component> {
+        id.checked_sub(1)
+            .and_then(|index| self.components.get(index as usize))
+    }
+
+    #[inline]
+    pub fn component_at(&self, point: Point) -> Option<&Component> {
+        if !self.ids.is_in_bounds(point) {
+            return None;
+        }
+        self.component(self.ids[point])
+    }
+}
+
+impl<T> Grid<T> {
+    // all cells reachable from start through passable cells, start included
+    // empty if the start itself is not passable
+    pub fn flood_fill<F>(&self, start: Point, passable: F, connectivity: &[Direction]) -> Vec<Point>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut seen = Grid::new(self.width, self.height, false);
+        self.fill(start, &passable, connectivity, &mut seen)
+    }
+
+    fn fill<F>(
+        &self,
+        start: Point,
+        passable: &F,
+        connectivity: &[Direction],
+        seen: &mut Grid<bool>,
+    ) -> Vec<Point>
+    where
+        F: Fn(&T) -> bool,
+    {
+        if !self.is_in_bounds(start) || seen[start] || !passable(&self[start]) {
+            return vec![];
+        }
+
+        let mut region = vec![];
+        let mut stack = vec![start];
+        seen[start] = true;
+
+        while let Some(point) = stack.pop() {
+            region.push(point);
+            for &direction in connectivity {
+                let next = point + direction;
+                if self.is_in_bounds(next) && !seen[next] && passable(&self[next]) {
+                    seen[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+
+        region
+    }
+
+    // label every group of connected cells matching the predicate with its own id, starting at 1
+    pub fn label_components<F>(&self, predicate: F, connectivity: &[Direction]) -> Labels
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut seen = Grid::new(self.width, self.height, false);
+        let mut ids = Grid::new(self.width, self.height, 0);
+        let mut components = vec![];
+
+        for line in 0..self.height {
+            for column in 0..self.width {
+                let region = self.fill(
+                    Point::new_usize(line, column),
+                    &predicate,
+                    connectivity,
+                    &mut seen,
+                );
+                if region.is_empty() {
+                    continue;
+                }
+
+                let id = components.len() as u32 + 1;
+                for &point in &region {
+                    ids[point] = id;
+                }
+                components.push(Component {
+                    id,
+                    size: region.len(),
+                    min: region.iter().fold(region[0], |min, p| min.min(p)),
+                    max: region.iter().fold(region[0], |max, p| max.max(p)),
+                    perimeter: 0,
+                });
+            }
+        }
+
+        // each side of a cell facing a cell of another id is part of the perimeter
+        for (point, &id) in ids.iter_item_and_position() {
+            if id == 0 {
+                continue;
+            }
+            let sides = ORTHOGONAL
+                .iter()
+                .filter(|&&d| {
+                    let next = point + d;
+                    !ids.is_in_bounds(next) || ids[next] != id
+                })
+                .count();
+            components[id as usize - 1].perimeter += sides;
+        }
+
+        Labels { ids, components }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::direction::ALL_AROUND;
+
+    const GARDEN: &str = "\
+AAAA
+BBCD
+BBCC
+EEEC";
+
+    #[test]
+    pub fn test_flood_fill() {
+        let grid = Grid::new_from_str("..#.\n.##.\n#...\n", &|c| c);
+        let mut region = grid.flood_fill(Point::new(0, 0), |&c| c == '.', &ORTHOGONAL);
+        region.sort_by_key(|p| (p.line, p.column));
+        assert_eq!(
+            region,
+            vec![Point::new(0, 0), Point::new(0, 1), Point::new(1, 0)]
+        );
+
+        // diagonal steps reach the right side
+        let region = grid.flood_fill(Point::new(0, 0), |&c| c == '.', &ALL_AROUND);
+        assert_eq!(region.len(), 8);
+
+        assert!(grid
+            .flood_fill(Point::new(0, 2), |&c| c == '.', &ORTHOGONAL)
+            .is_empty());
+    }
+
+    #[test]
+    pub fn test_label_components() {
+        let grid = Grid::new_from_str(GARDEN, &|c| c);
+        let labels = grid.label_components(|&c| c != 'A', &ORTHOGONAL);
+
+        // B, C with D and E form a single component when only A is excluded
+        assert_eq!(labels.components.len(), 1);
+        assert_eq!(labels.ids[Point::new(0, 0)], 0);
+        assert_eq!(labels.component_at(Point::new(0, 0)), None);
+
+        let component = labels.component_at(Point::new(3, 3)).unwrap();
+        assert_eq!(component.size, 12);
+        assert_eq!(component.min, Point::new(1, 0));
+        assert_eq!(component.max, Point::new(3, 3));
+        assert_eq!(component.perimeter, 14);
+    }
+
+    #[test]
+    pub fn test_label_components_connectivity() {
+        let grid = Grid::new_from_str("#.#\n.#.\n#.#", &|c| c);
+
+        let orthogonal = grid.label_components(|&c| c == '#', &ORTHOGONAL);
+        assert_eq!(orthogonal.components.len(), 5);
+        assert!(orthogonal
+            .components
+            .iter()
+            .all(|c| c.size == 1 && c.perimeter == 4));
+
+        let all_around = grid.label_components(|&c| c == '#', &ALL_AROUND);
+        assert_eq!(all_around.components.len(), 1);
+        let component = all_around.component(1).unwrap();
+        assert_eq!(component.size, 5);
+        assert_eq!(component.perimeter, 20);
+        assert_eq!(component.min, Point::new(0, 0));
+        assert_eq!(component.max, Point::new(2, 2));
+    }
+}
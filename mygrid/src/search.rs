@@ -0,0 +1,561 @@
+// search
+// generic breadth first search, dijkstra and A* over user defined states, e.g. a point and a direction
+// the best known cost and the parent of every reached state are kept in a `Visited` store,
+// either a hash map or, for states that map to a grid cell, a flat array backed by a `Grid`
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use crate::grid::Grid;
+use crate::point::Point;
+
+// how a state was reached
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Visit<S, C> {
+    pub cost: C,
+    pub parent: Option<S>,
+}
+
+// storage of the visited states
+pub trait Visited<S, V> {
+    fn get(&self, state: &S) -> Option<&V>;
+    // false if the state can't be stored, e.g. it is off the grid, searches skip such states
+    fn insert(&mut self, state: S, value: V) -> bool;
+}
+
+impl<S: Eq + Hash, V> Visited<S, V> for HashMap<S, V> {
+    #[inline]
+    fn get(&self, state: &S) -> Option<&V> {
+        HashMap::get(self, state)
+    }
+
+    #[inline]
+    fn insert(&mut self, state: S, value: V) -> bool {
+        HashMap::insert(self, state, value);
+        true
+    }
+}
+
+// a state that lives on a grid cell, with a number of layers per cell
+// e.g. a walker on a point facing one of 4 directions has 4 layers
+pub trait GridState {
+    fn point(&self) -> Point;
+
+    #[inline]
+    fn layer(&self) -> usize {
+        0
+    }
+}
+
+impl GridState for Point {
+    #[inline]
+    fn point(&self) -> Point {
+        *self
+    }
+}
+
+// visited store backed by a grid, each cell holds `layers` values next to each other
+// much faster than hashing states on dense searches
+pub struct GridVisited<V> {
+    grid: Grid<Option<V>>,
+    layers: usize,
+}
+
+impl<V> GridVisited<V> {
+    #[inline]
+    pub fn new(width: usize, height: usize, layers: usize) -> Self {
+        let content = (0..width * height * layers).map(|_| None).collect();
+        Self {
+            grid: Grid::from_vec(content, width * layers),
+            layers,
+        }
+    }
+
+    // same size as another grid, with a single layer
+    #[inline]
+    pub fn like<T>(grid: &Grid<T>) -> Self {
+        Self::new(grid.width, grid.height, 1)
+    }
+
+    #[inline]
+    fn position<S: GridState>(&self, state: &S) -> Option<Point> {
+        let point = state.point();
+        let layer = state.layer();
+        assert!(
+            layer < self.layers,
+            "layer {} out of {}",
+            layer,
+            self.layers
+        );
+        let position = Point::new(
+            point.line,
+            point.column * self.layers as isize + layer as isize,
+        );
+        if point.column >= 0 && self.grid.is_in_bounds(position) {
+            Some(position)
+        } else {
+            None
+        }
+    }
+}
+
+impl<S: GridState, V> Visited<S, V> for GridVisited<V> {
+    #[inline]
+    fn get(&self, state: &S) -> Option<&V> {
+        self.position(state).and_then(|p| self.grid[p].as_ref())
+    }
+
+    #[inline]
+    fn insert(&mut self, state: S, value: V) -> bool {
+        match self.position(&state) {
+            Some(position) => {
+                self.grid[position] = Some(value);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+// outcome of a search
+pub struct Search<S, C, M> {
+    visited: M,
+    goal: Option<S>,
+    _cost: std::marker::PhantomData<C>,
+}
+
+impl<S, C, M> Search<S, C, M>
+where
+    S: Copy,
+    C: Copy,
+    M: Visited<S, Visit<S, C>>,
+{
+    #[inline]
+    fn new(visited: M) -> Self {
+        Self {
+            visited,
+            goal: None,
+            _cost: std::marker::PhantomData,
+        }
+    }
+
+    // the first goal state that was reached, the cheapest one for dijkstra and A*
+    #[inline]
+    pub fn goal(&self) -> Option<S> {
+        self.goal
+    }
+
+    #[inline]
+    pub fn goal_cost(&self) -> Option<C> {
+        self.goal.and_then(|goal| self.cost(&goal))
+    }
+
+    // best known cost of a state, `None` if it was never reached
+    #[inline]
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.visited.get(state).map(|visit| visit.cost)
+    }
+
+    // the states from a start to the given state, both included
+    pub fn path_to(&self, state: S) -> Option<Vec<S>> {
+        let mut path = vec![state];
+        let mut visit = self.visited.get(&state)?;
+        while let Some(parent) = visit.parent {
+            path.push(parent);
+            visit = self.visited.get(&parent)?;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    #[inline]
+    pub fn path_to_goal(&self) -> Option<Vec<S>> {
+        self.goal.and_then(|goal| self.path_to(goal))
+    }
+
+    #[inline]
+    pub fn into_visited(self) -> M {
+        self.visited
+    }
+}
+
+// breadth first search, every step costs 1
+// stops at the first goal, pass `|_| false` to reach every state
+pub fn bfs<S, M, N, I, G>(
+    starts: impl IntoIterator<Item = S>,
+    visited: M,
+    mut neighbors: N,
+    mut is_goal: G,
+) -> Search<S, usize, M>
+where
+    S: Copy,
+    M: Visited<S, Visit<S, usize>>,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool,
+{
+    let mut search = Search::new(visited);
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if search.visited.get(&start).is_none()
+            && search.visited.insert(
+                start,
+                Visit {
+                    cost: 0,
+                    parent: None,
+                },
+            )
+        {
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((state, cost)) = queue.pop_front() {
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+
+        for next in neighbors(&state) {
+            if search.visited.get(&next).is_none()
+                && search.visited.insert(
+                    next,
+                    Visit {
+                        cost: cost + 1,
+                        parent: Some(state),
+                    },
+                )
+            {
+                queue.push_back((next, cost + 1));
+            }
+        }
+    }
+
+    search
+}
+
+// entry of the priority queue, ordered by priority only, lowest first
+struct Queued<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // reversed, the binary heap is a max heap
+        other.priority.cmp(&self.priority)
+    }
+}
+
+// dijkstra, neighbors are returned with the cost of the step to them
+// costs must not be negative, `C::default()` is the cost of the starts
+pub fn dijkstra<S, C, M, N, I, G>(
+    starts: impl IntoIterator<Item = S>,
+    visited: M,
+    neighbors: N,
+    is_goal: G,
+) -> Search<S, C, M>
+where
+    S: Copy,
+    C: Copy + Ord + Default + Add<Output = C>,
+    M: Visited<S, Visit<S, C>>,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    G: FnMut(&S) -> bool,
+{
+    astar(starts, visited, neighbors, |_| C::default(), is_goal)
+}
+
+// A*, the heuristic estimates the remaining cost to a goal
+// it must never overestimate it, otherwise the goal found may not be the cheapest
+pub fn astar<S, C, M, N, I, H, G>(
+    starts: impl IntoIterator<Item = S>,
+    visited: M,
+    mut neighbors: N,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Search<S, C, M>
+where
+    S: Copy,
+    C: Copy + Ord + Default + Add<Output = C>,
+    M: Visited<S, Visit<S, C>>,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    H: FnMut(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    let mut search = Search::new(visited);
+    let mut queue = BinaryHeap::new();
+
+    for start in starts {
+        let cost = C::default();
+        if !search.visited.insert(start, Visit { cost, parent: None }) {
+            continue;
+        }
+        queue.push(Queued {
+            priority: cost + heuristic(&start),
+            cost,
+            state: start,
+        });
+    }
+
+    while let Some(Queued { cost, state, .. }) = queue.pop() {
+        // a cheaper way to this state was found after it was queued
+        if search.cost(&state).is_some_and(|best| best < cost) {
+            continue;
+        }
+
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+
+        for (next, step) in neighbors(&state) {
+            let next_cost = cost + step;
+            if search.cost(&next).is_some_and(|best| best <= next_cost) {
+                continue;
+            }
+
+            let stored = search.visited.insert(
+                next,
+                Visit {
+                    cost: next_cost,
+                    parent: Some(state),
+                },
+            );
+            if !stored {
+                continue;
+            }
+            queue.push(Queued {
+                priority: next_cost + heuristic(&next),
+                cost: next_cost,
+                state: next,
+            });
+        }
+    }
+
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::direction::{Direction, DOWN, LEFT, ORTHOGONAL, RIGHT, UP};
+
+    const MAZE: &str = "\
+S.#.....
+.##.###.
+....#...
+.####.#.
+......#E";
+
+    fn open_neighbors<'a>(grid: &'a Grid<char>) -> impl Fn(&Point) -> Vec<Point> + 'a {
+        move |&point| {
+            ORTHOGONAL
+                .iter()
+                .map(|&d| point + d)
+                .filter(|&p| grid.is_in_bounds(p) && grid[p] != '#')
+                .collect()
+        }
+    }
+
+    #[test]
+    pub fn test_bfs() {
+        let (grid, starts) = Grid::<char>::parse_with_markers(MAZE, |c| c == 'S').unwrap();
+        let start = starts[0];
+        let end = Point::new(4, 7);
+
+        let search = bfs(
+            [start],
+            GridVisited::like(&grid),
+            open_neighbors(&grid),
+            |&p| p == end,
+        );
+        assert_eq!(search.goal(), Some(end));
+        assert_eq!(search.goal_cost(), Some(15));
+
+        let path = search.path_to_goal().unwrap();
+        assert_eq!(path.len(), 16);
+        assert_eq!(path[0], start);
+        assert_eq!(path[15], end);
+
+        // without a goal every reachable cell is visited, same result with a hash map
+        let search = bfs([start], HashMap::new(), open_neighbors(&grid), |_| false);
+        assert_eq!(search.goal(), None);
+        assert_eq!(search.cost(&end), Some(15));
+        assert_eq!(search.cost(&Point::new(0, 2)), None);
+    }
+
+    #[test]
+    pub fn test_dijkstra_and_astar() {
+        let grid = Grid::new_from_str("1163\n1381\n2136\n3694", &|c| c.to_digit(10).unwrap());
+        let end = Point::new(3, 3);
+        let neighbors = |&p: &Point| {
+            ORTHOGONAL
+                .iter()
+                .map(|&d| p + d)
+                .filter(|&n| grid.is_in_bounds(n))
+                .map(|n| (n, grid[n]))
+                .collect::<Vec<_>>()
+        };
+
+        let search = dijkstra(
+            [Point::new(0, 0)],
+            GridVisited::like(&grid),
+            neighbors,
+            |&p| p == end,
+        );
+        assert_eq!(search.goal_cost(), Some(17));
+
+        let manhattan =
+            |p: &Point| ((end.line - p.line).abs() + (end.column - p.column).abs()) as u32;
+        let search = astar(
+            [Point::new(0, 0)],
+            HashMap::new(),
+            neighbors,
+            manhattan,
+            |&p| p == end,
+        );
+        assert_eq!(search.goal_cost(), Some(17));
+        let path = search.path_to_goal().unwrap();
+        let cost: u32 = path.iter().skip(1).map(|&p| grid[p]).sum();
+        assert_eq!(cost, 17);
+    }
+
+    // the crucible of 2023 day 17, a state wrapping a point and a direction
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+    struct Crucible {
+        point: Point,
+        direction: Direction,
+        straight: usize,
+    }
+
+    const MAX_STRAIGHT: usize = 10;
+
+    impl GridState for Crucible {
+        fn point(&self) -> Point {
+            self.point
+        }
+
+        fn layer(&self) -> usize {
+            let direction = [UP, DOWN, LEFT, RIGHT]
+                .iter()
+                .position(|&d| d == self.direction)
+                .unwrap();
+            direction * (MAX_STRAIGHT + 1) + self.straight
+        }
+    }
+
+    fn min_heat_loss(input: &str, min: usize, max: usize) -> Option<u32> {
+        let grid = Grid::new_from_str(input, &|c| c.to_digit(10).unwrap());
+        let end = Point::new_usize(grid.height - 1, grid.width - 1);
+        let starts = [RIGHT, DOWN].map(|direction| Crucible {
+            point: Point::new(0, 0),
+            direction,
+            straight: 0,
+        });
+
+        let neighbors = |c: &Crucible| {
+            let mut next = vec![];
+            if c.straight < max {
+                next.push((c.direction, c.straight + 1));
+            }
+            if c.straight >= min {
+                next.push((c.direction.rotate_clockwise(), 1));
+                next.push((c.direction.rotate_counterclockwise(), 1));
+            }
+            next.into_iter()
+                .map(|(direction, straight)| Crucible {
+                    point: c.point + direction,
+                    direction,
+                    straight,
+                })
+                .filter(|n| grid.is_in_bounds(n.point))
+                .map(|n| (n, grid[n.point]))
+                .collect::<Vec<_>>()
+        };
+
+        let visited = GridVisited::new(grid.width, grid.height, 4 * (MAX_STRAIGHT + 1));
+        dijkstra(starts, visited, neighbors, |c| {
+            c.point == end && c.straight >= min
+        })
+        .goal_cost()
+    }
+
+    #[test]
+    pub fn test_dijkstra_layered_states() {
+        let input = "\
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+        assert_eq!(min_heat_loss(input, 0, 3), Some(102));
+        assert_eq!(min_heat_loss(input, 4, 10), Some(94));
+    }
+
+    #[test]
+    pub fn test_grid_visited_bounds() {
+        let mut visited: GridVisited<u8> = GridVisited::new(2, 2, 3);
+        let state = Point::new(1, 1);
+        assert_eq!(visited.get(&state), None);
+        assert!(visited.insert(state, 7));
+        assert_eq!(visited.get(&state), Some(&7));
+        assert_eq!(visited.get(&Point::new(0, 2)), None);
+        assert_eq!(visited.get(&Point::new(-1, 0)), None);
+        assert!(!visited.insert(Point::new(0, 2), 1));
+        assert!(!visited.insert(Point::new(-1, 0), 1));
+    }
+
+    #[test]
+    pub fn test_search_skips_states_off_the_grid() {
+        let grid = Grid::new_from_str("...\n...", &|c| c);
+        let end = Point::new(1, 2);
+        // neighbors are not bounds checked, states off the grid are skipped by the searches
+        let neighbors = |&p: &Point| ORTHOGONAL.iter().map(|&d| p + d).collect::<Vec<_>>();
+
+        let search = bfs(
+            vec![Point::new(0, 0), Point::new(-1, 0)],
+            GridVisited::like(&grid),
+            neighbors,
+            |&p| p == end,
+        );
+        assert_eq!(search.goal_cost(), Some(3));
+        assert_eq!(search.cost(&Point::new(-1, 0)), None);
+
+        let search = dijkstra(
+            vec![Point::new(0, 0)],
+            GridVisited::like(&grid),
+            |p: &Point| neighbors(p).into_iter().map(|n| (n, 1)),
+            |&p| p == end,
+        );
+        assert_eq!(search.goal_cost(), Some(3));
+        assert_eq!(search.path_to_goal().unwrap().len(), 4);
+    }
+}
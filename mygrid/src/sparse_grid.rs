@@ -0,0 +1,290 @@
+// sparse grid
+// a grid backed by a hash map, for content that grows in any direction from an arbitrary start
+// like the trench of 2023 day 18, points can be negative and no size has to be guessed upfront
+// the bounding box of the set cells is tracked, so it can be turned into a dense grid at the end
+
+use std::collections::HashMap;
+use std::iter::FromIterator;
+use std::ops::{Index, IndexMut};
+
+use crate::grid::Grid;
+use crate::point::Point;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    content: HashMap<Point, T>,
+    // bounding box of all the cells ever set, both corners included
+    // removing cells does not shrink it
+    bounds: Option<(Point, Point)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            content: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.content.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.content.is_empty()
+    }
+
+    #[inline]
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (min.min(&point), max.max(&point)),
+            None => (point, point),
+        });
+        self.content.insert(point, value)
+    }
+
+    #[inline]
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        self.content.remove(&point)
+    }
+
+    #[inline]
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.content.get(&point)
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.content.get_mut(&point)
+    }
+
+    #[inline]
+    pub fn contains(&self, point: Point) -> bool {
+        self.content.contains_key(&point)
+    }
+
+    #[inline]
+    pub fn min(&self) -> Option<Point> {
+        self.bounds.map(|(min, _)| min)
+    }
+
+    #[inline]
+    pub fn max(&self) -> Option<Point> {
+        self.bounds.map(|(_, max)| max)
+    }
+
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.bounds
+            .map_or(0, |(min, max)| (max.column - min.column + 1) as usize)
+    }
+
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.bounds
+            .map_or(0, |(min, max)| (max.line - min.line + 1) as usize)
+    }
+
+    // whether the point is inside the bounding box, set or not
+    #[inline]
+    pub fn is_in_bounds(&self, point: Point) -> bool {
+        self.bounds.is_some_and(|(min, max)| {
+            point.line >= min.line
+                && point.line <= max.line
+                && point.column >= min.column
+                && point.column <= max.column
+        })
+    }
+
+    // the iterators only go over the set cells, in no particular order
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.content.values()
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.content.values_mut()
+    }
+
+    #[inline]
+    pub fn iter_item_and_position(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.content.iter().map(|(&point, t)| (point, t))
+    }
+
+    // a dense grid covering the bounding box, unset cells are filled with default
+    // also returns the offset, the point of the sparse grid at (0, 0) in the dense one
+    #[inline]
+    pub fn to_grid(&self, default: T) -> (Grid<T>, Point)
+    where
+        T: Copy,
+    {
+        self.to_grid_with_margin(default, 0)
+    }
+
+    // same as `to_grid` with `margin` more cells of default on each side,
+    // e.g. to flood fill the outside from the corner
+    pub fn to_grid_with_margin(&self, default: T, margin: usize) -> (Grid<T>, Point)
+    where
+        T: Copy,
+    {
+        let Some((min, _)) = self.bounds else {
+            return (Grid::new(0, 0, default), Point::new(0, 0));
+        };
+
+        let offset = Point::new(min.line - margin as isize, min.column - margin as isize);
+        let mut grid = Grid::new(
+            self.width() + 2 * margin,
+            self.height() + 2 * margin,
+            default,
+        );
+        for (&point, &t) in &self.content {
+            grid[Point::new(point.line - offset.line, point.column - offset.column)] = t;
+        }
+        (grid, offset)
+    }
+
+    // format every cell of the bounding box, unset cells are given as None
+    #[inline]
+    pub fn to_fmt<F>(&self, f: F) -> Grid<String>
+    where
+        F: Fn(Point, Option<&T>) -> String,
+    {
+        let Some((min, _)) = self.bounds else {
+            return Grid::from_vec(vec![], 1);
+        };
+
+        let mut content = Vec::with_capacity(self.width() * self.height());
+        for line in 0..self.height() as isize {
+            for column in 0..self.width() as isize {
+                let point = Point::new(min.line + line, min.column + column);
+                content.push(f(point, self.get(point)));
+            }
+        }
+        Grid::from_vec(content, self.width())
+    }
+}
+
+impl<T> Index<Point> for SparseGrid<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, point: Point) -> &Self::Output {
+        &self.content[&point]
+    }
+}
+
+impl<T> IndexMut<Point> for SparseGrid<T> {
+    #[inline]
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        self.content
+            .get_mut(&point)
+            .expect("no value at this point")
+    }
+}
+
+impl<T> Extend<(Point, T)> for SparseGrid<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (Point, T)>>(&mut self, iter: I) {
+        for (point, t) in iter {
+            self.insert(point, t);
+        }
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_sparse_grid_bounds() {
+        let mut grid = SparseGrid::new();
+        assert!(grid.is_empty());
+        assert_eq!(grid.min(), None);
+        assert_eq!(grid.width(), 0);
+        assert!(!grid.is_in_bounds(Point::new(0, 0)));
+
+        grid.insert(Point::new(0, 0), '#');
+        grid.insert(Point::new(-2, 3), '#');
+        grid.insert(Point::new(1, -1), '#');
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid.min(), Some(Point::new(-2, -1)));
+        assert_eq!(grid.max(), Some(Point::new(1, 3)));
+        assert_eq!(grid.width(), 5);
+        assert_eq!(grid.height(), 4);
+        assert!(grid.is_in_bounds(Point::new(-2, -1)));
+        assert!(!grid.is_in_bounds(Point::new(2, 0)));
+
+        // removing does not shrink the bounding box
+        assert_eq!(grid.remove(Point::new(-2, 3)), Some('#'));
+        assert!(!grid.contains(Point::new(-2, 3)));
+        assert_eq!(grid.max(), Some(Point::new(1, 3)));
+    }
+
+    #[test]
+    pub fn test_sparse_grid_index() {
+        let mut grid: SparseGrid<u32> = vec![(Point::new(-1, -1), 1), (Point::new(4, 2), 2)]
+            .into_iter()
+            .collect();
+        assert_eq!(grid[Point::new(-1, -1)], 1);
+        grid[Point::new(4, 2)] += 10;
+        assert_eq!(grid.get(Point::new(4, 2)), Some(&12));
+        assert_eq!(grid.get(Point::new(0, 0)), None);
+        *grid.get_mut(Point::new(-1, -1)).unwrap() = 5;
+        assert_eq!(grid.iter().sum::<u32>(), 17);
+
+        let mut positions = grid
+            .iter_item_and_position()
+            .map(|(p, &t)| (p, t))
+            .collect::<Vec<_>>();
+        positions.sort_by_key(|&(p, _)| (p.line, p.column));
+        assert_eq!(
+            positions,
+            vec![(Point::new(-1, -1), 5), (Point::new(4, 2), 12)]
+        );
+    }
+
+    #[test]
+    pub fn test_sparse_grid_to_grid() {
+        let grid: SparseGrid<char> = vec![
+            (Point::new(-1, 0), 'a'),
+            (Point::new(0, -1), 'b'),
+            (Point::new(0, 1), 'c'),
+        ]
+        .into_iter()
+        .collect();
+
+        let (dense, offset) = grid.to_grid('.');
+        assert_eq!(offset, Point::new(-1, -1));
+        let dense_fmt = dense.to_fmt(|_, c| c.to_string());
+        assert_eq!(format!("{}", dense_fmt), ".a.\nb.c\n");
+
+        let (dense, offset) = grid.to_grid_with_margin('.', 1);
+        assert_eq!(offset, Point::new(-2, -2));
+        assert_eq!(dense.width, 5);
+        assert_eq!(dense.height, 4);
+        assert_eq!(dense[Point::new(1, 2)], 'a');
+
+        let sparse_fmt = grid.to_fmt(|_, c| c.map_or("?".to_owned(), |c| c.to_string()));
+        assert_eq!(format!("{}", sparse_fmt), "?a?\nb?c\n");
+    }
+}
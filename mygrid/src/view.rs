@@ -0,0 +1,399 @@
+// grid views
+// transposed, rotated and flipped grids, either copied or as views that only remap indices
+// an algorithm written for one orientation, like tilting rocks north in 2023 day 14,
+// can run on a view to work in any other orientation
+
+use std::ops::{Index, IndexMut};
+
+use crate::grid::Grid;
+use crate::point::Point;
+
+// the 8 ways to lay a grid down, named after the transformation from the grid to the view
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Orientation {
+    Identity,
+    // mirror along the main diagonal, lines become columns
+    Transpose,
+    RotateCw,
+    RotateCcw,
+    Rotate180,
+    // mirror left to right
+    FlipH,
+    // mirror top to bottom
+    FlipV,
+    // mirror along the other diagonal
+    AntiTranspose,
+}
+
+pub const ORIENTATIONS: [Orientation; 8] = [
+    Orientation::Identity,
+    Orientation::Transpose,
+    Orientation::RotateCw,
+    Orientation::RotateCcw,
+    Orientation::Rotate180,
+    Orientation::FlipH,
+    Orientation::FlipV,
+    Orientation::AntiTranspose,
+];
+
+impl Orientation {
+    #[inline]
+    pub fn swaps_axes(self) -> bool {
+        matches!(
+            self,
+            Orientation::Transpose
+                | Orientation::RotateCw
+                | Orientation::RotateCcw
+                | Orientation::AntiTranspose
+        )
+    }
+
+    // width and height of the view of a grid of the given size
+    #[inline]
+    pub fn view_size(self, width: usize, height: usize) -> (usize, usize) {
+        if self.swaps_axes() {
+            (height, width)
+        } else {
+            (width, height)
+        }
+    }
+
+    // the point of a grid of the given size that a point of the view maps to
+    #[inline]
+    pub fn to_grid_point(self, point: Point, width: usize, height: usize) -> Point {
+        let (last_line, last_column) = (height as isize - 1, width as isize - 1);
+        let (line, column) = (point.line, point.column);
+        match self {
+            Orientation::Identity => Point::new(line, column),
+            Orientation::Transpose => Point::new(column, line),
+            Orientation::RotateCw => Point::new(last_line - column, line),
+            Orientation::RotateCcw => Point::new(column, last_column - line),
+            Orientation::Rotate180 => Point::new(last_line - line, last_column - column),
+            Orientation::FlipH => Point::new(line, last_column - column),
+            Orientation::FlipV => Point::new(last_line - line, column),
+            Orientation::AntiTranspose => Point::new(last_line - column, last_column - line),
+        }
+    }
+}
+
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    orientation: Orientation,
+}
+
+// derive would require `T: Copy`, only the reference is copied
+impl<T> Clone for GridView<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+impl<'a, T> GridView<'a, T> {
+    #[inline]
+    pub fn new(grid: &'a Grid<T>, orientation: Orientation) -> Self {
+        Self { grid, orientation }
+    }
+
+    #[inline]
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.orientation
+            .view_size(self.grid.width, self.grid.height)
+            .0
+    }
+
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.orientation
+            .view_size(self.grid.width, self.grid.height)
+            .1
+    }
+
+    #[inline]
+    pub fn is_in_bounds(&self, point: Point) -> bool {
+        point.column >= 0
+            && point.column < (self.width() as isize)
+            && point.line >= 0
+            && point.line < (self.height() as isize)
+    }
+
+    // the point of the underlying grid a point of the view maps to
+    #[inline]
+    pub fn to_grid_point(&self, point: Point) -> Point {
+        self.orientation
+            .to_grid_point(point, self.grid.width, self.grid.height)
+    }
+
+    #[inline]
+    pub fn get(&self, point: Point) -> Option<&'a T> {
+        if self.is_in_bounds(point) {
+            Some(&self.grid[self.to_grid_point(point)])
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn row(&self, line: usize) -> impl Iterator<Item = &'a T> + 'a {
+        let view = *self;
+        (0..view.width())
+            .map(move |column| &view.grid[view.to_grid_point(Point::new_usize(line, column))])
+    }
+
+    #[inline]
+    pub fn iter_rows(&self) -> impl Iterator<Item = impl Iterator<Item = &'a T> + 'a> + 'a {
+        let view = *self;
+        (0..view.height()).map(move |line| view.row(line))
+    }
+
+    #[inline]
+    pub fn iter_item_and_position(&self) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        let view = *self;
+        (0..view.height()).flat_map(move |line| {
+            (0..view.width()).map(move |column| {
+                let point = Point::new_usize(line, column);
+                (point, &view.grid[view.to_grid_point(point)])
+            })
+        })
+    }
+
+    // copy the view into a new grid
+    #[inline]
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let content = self.iter_rows().flatten().cloned().collect::<Vec<_>>();
+        Grid::from_vec(content, self.width())
+    }
+}
+
+impl<T> Index<Point> for GridView<'_, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, point: Point) -> &Self::Output {
+        &self.grid[self.to_grid_point(point)]
+    }
+}
+
+pub struct GridViewMut<'a, T> {
+    grid: &'a mut Grid<T>,
+    orientation: Orientation,
+}
+
+impl<'a, T> GridViewMut<'a, T> {
+    #[inline]
+    pub fn new(grid: &'a mut Grid<T>, orientation: Orientation) -> Self {
+        Self { grid, orientation }
+    }
+
+    #[inline]
+    pub fn as_view(&self) -> GridView<'_, T> {
+        GridView::new(self.grid, self.orientation)
+    }
+
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.as_view().width()
+    }
+
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.as_view().height()
+    }
+
+    #[inline]
+    pub fn is_in_bounds(&self, point: Point) -> bool {
+        self.as_view().is_in_bounds(point)
+    }
+
+    #[inline]
+    pub fn to_grid_point(&self, point: Point) -> Point {
+        self.as_view().to_grid_point(point)
+    }
+
+    #[inline]
+    pub fn get(&self, point: Point) -> Option<&T> {
+        if self.is_in_bounds(point) {
+            Some(&self.grid[self.to_grid_point(point)])
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.is_in_bounds(point) {
+            let point = self.to_grid_point(point);
+            Some(&mut self.grid[point])
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn swap(&mut self, a: Point, b: Point) {
+        let (a, b) = (self.to_grid_point(a), self.to_grid_point(b));
+        self.grid.swap(a, b);
+    }
+}
+
+impl<T> Index<Point> for GridViewMut<'_, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, point: Point) -> &Self::Output {
+        &self.grid[self.to_grid_point(point)]
+    }
+}
+
+impl<T> IndexMut<Point> for GridViewMut<'_, T> {
+    #[inline]
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        let point = self.to_grid_point(point);
+        &mut self.grid[point]
+    }
+}
+
+impl<T> Grid<T> {
+    #[inline]
+    pub fn view(&self, orientation: Orientation) -> GridView<'_, T> {
+        GridView::new(self, orientation)
+    }
+
+    #[inline]
+    pub fn view_mut(&mut self, orientation: Orientation) -> GridViewMut<'_, T> {
+        GridViewMut::new(self, orientation)
+    }
+
+    // a copy of the grid in the given orientation
+    #[inline]
+    pub fn transformed(&self, orientation: Orientation) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.view(orientation).to_grid()
+    }
+
+    #[inline]
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.transformed(Orientation::Transpose)
+    }
+
+    #[inline]
+    pub fn rotate_cw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.transformed(Orientation::RotateCw)
+    }
+
+    #[inline]
+    pub fn rotate_ccw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.transformed(Orientation::RotateCcw)
+    }
+
+    #[inline]
+    pub fn flip_h(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.transformed(Orientation::FlipH)
+    }
+
+    #[inline]
+    pub fn flip_v(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.transformed(Orientation::FlipV)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_string(grid: &Grid<char>) -> String {
+        format!("{}", grid.to_fmt(|_, c| c.to_string()))
+    }
+
+    #[test]
+    pub fn test_grid_transforms() {
+        let grid = Grid::new_from_str("123\n456", &|c| c);
+        assert_eq!(to_string(&grid.transpose()), "14\n25\n36\n");
+        assert_eq!(to_string(&grid.rotate_cw()), "41\n52\n63\n");
+        assert_eq!(to_string(&grid.rotate_ccw()), "36\n25\n14\n");
+        assert_eq!(to_string(&grid.flip_h()), "321\n654\n");
+        assert_eq!(to_string(&grid.flip_v()), "456\n123\n");
+        assert_eq!(
+            to_string(&grid.transformed(Orientation::Rotate180)),
+            "654\n321\n"
+        );
+        assert_eq!(
+            to_string(&grid.transformed(Orientation::AntiTranspose)),
+            "63\n52\n41\n"
+        );
+        assert_eq!(grid.transformed(Orientation::Identity), grid);
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(grid.transpose().transpose(), grid);
+    }
+
+    #[test]
+    pub fn test_grid_view() {
+        let grid = Grid::new_from_str("123\n456", &|c| c);
+        let view = grid.view(Orientation::RotateCw);
+        assert_eq!(view.width(), 2);
+        assert_eq!(view.height(), 3);
+        assert_eq!(view[Point::new(0, 0)], '4');
+        assert_eq!(view[Point::new(2, 1)], '3');
+        assert_eq!(view.get(Point::new(0, 2)), None);
+        assert_eq!(view.to_grid_point(Point::new(1, 1)), Point::new(0, 1));
+        assert_eq!(view.row(1).collect::<String>(), "52");
+
+        let rows = view
+            .iter_rows()
+            .map(|row| row.collect::<String>())
+            .collect::<Vec<_>>();
+        assert_eq!(rows, vec!["41", "52", "63"]);
+
+        let (last, &c) = view.iter_item_and_position().last().unwrap();
+        assert_eq!((last, c), (Point::new(2, 1), '3'));
+
+        // every view agrees with its copy
+        for orientation in ORIENTATIONS {
+            let view = grid.view(orientation);
+            let copy = grid.transformed(orientation);
+            assert_eq!(view.width(), copy.width);
+            assert_eq!(view.height(), copy.height);
+            for (point, c) in copy.iter_item_and_position() {
+                assert_eq!(view[point], *c);
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_grid_view_mut() {
+        let mut grid = Grid::new_from_str("123\n456", &|c| c);
+        let mut view = grid.view_mut(Orientation::FlipV);
+        view[Point::new(0, 0)] = 'a';
+        *view.get_mut(Point::new(1, 2)).unwrap() = 'b';
+        view.swap(Point::new(0, 1), Point::new(1, 1));
+        assert_eq!(view.get_mut(Point::new(2, 0)), None);
+        assert_eq!(to_string(&grid), "15b\na26\n");
+    }
+}
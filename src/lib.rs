@@ -1,4 +1,5 @@
 mod day;
 pub mod template;
+pub mod y2023;
 
 pub use day::*;
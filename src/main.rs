@@ -1,17 +1,36 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
+use advent_of_code::template::commands::{
+    all, bench_report, completions, countdown, dashboard, download, inputs, progress, read,
+    scaffold, search, solve, stats, watch,
+};
 use args::{parse, AppArguments};
 
 mod args {
-    use std::process;
-
+    use advent_of_code::template::cli::{
+        self, Error, Matches, Shell, SolutionArgs, COLUMNS, DAY, INPUTS_ACTION, KEY_FILE,
+        MARK_SLOWEST, PART, README, RELEASE, SHELL, SORT, STATS_README, TAG, TIME, YEAR,
+    };
+    use advent_of_code::template::commands::inputs;
+    use advent_of_code::template::config::{self, Overrides};
+    use advent_of_code::template::readme_benchmarks::{self, Column, TableOptions};
+    use advent_of_code::template::tags;
     use advent_of_code::Day;
+    use std::{path::PathBuf, str::FromStr};
 
     pub enum AppArguments {
+        Await,
+        BenchReport {
+            tags: Vec<String>,
+        },
         Download {
             day: Day,
         },
+        Inputs {
+            action: inputs::Action,
+            key_file: Option<PathBuf>,
+        },
         Read {
             day: Day,
+            part: Option<u8>,
         },
         Scaffold {
             day: Day,
@@ -19,74 +38,169 @@ mod args {
         Solve {
             day: Day,
             release: bool,
-            time: bool,
-            submit: Option<u8>,
+            solution_args: SolutionArgs,
         },
         All {
             release: bool,
             time: bool,
+            table_options: TableOptions,
+            tags: Vec<String>,
+        },
+        Search {
+            terms: Vec<String>,
+            tags: Vec<String>,
+        },
+        WatchDay {
+            day: Day,
+            release: bool,
+        },
+        Progress {
+            readme: bool,
+        },
+        Stats {
+            readme: bool,
+        },
+        Dashboard,
+        Completions {
+            shell: Shell,
         },
     }
 
-    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
-        let mut args = pico_args::Arguments::from_env();
+    /// Parse the command line against the commands declared in [`cli::COMMANDS`].
+    pub fn parse() -> Result<AppArguments, Error> {
+        let (command, matches) = cli::parse_command(std::env::args().skip(1))?;
+        config::init(Overrides {
+            year: matches.value(&YEAR)?,
+        });
 
-        let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-                time: args.contains("--time"),
+        let app_args = match command.name {
+            "all" => AppArguments::All {
+                release: matches.flag(&RELEASE),
+                time: matches.flag(&TIME),
+                table_options: parse_table_options(&matches)?,
+                tags: parse_tags(&matches)?,
+            },
+            "time" => AppArguments::All {
+                release: true,
+                time: true,
+                table_options: parse_table_options(&matches)?,
+                tags: vec![],
+            },
+            "await" => AppArguments::Await,
+            "bench-report" => AppArguments::BenchReport {
+                tags: parse_tags(&matches)?,
+            },
+            "download" => AppArguments::Download {
+                day: matches.required(&DAY)?,
+            },
+            "inputs" => AppArguments::Inputs {
+                action: matches.required(&INPUTS_ACTION)?,
+                key_file: matches.value(&KEY_FILE)?,
+            },
+            "read" => AppArguments::Read {
+                day: matches.required(&DAY)?,
+                part: matches.part(&PART)?,
+            },
+            "scaffold" => AppArguments::Scaffold {
+                day: matches.required(&DAY)?,
+            },
+            "solve" => AppArguments::Solve {
+                day: matches.required(&DAY)?,
+                release: matches.flag(&RELEASE),
+                solution_args: SolutionArgs::from_matches(&matches)?,
+            },
+            "watch-day" => AppArguments::WatchDay {
+                day: matches.required(&DAY)?,
+                release: matches.flag(&RELEASE),
             },
-            Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+            "progress" => AppArguments::Progress {
+                readme: matches.flag(&README),
             },
-            Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+            "search" => AppArguments::Search {
+                terms: matches.rest().to_vec(),
+                tags: parse_tags(&matches)?,
             },
-            Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+            "stats" => AppArguments::Stats {
+                readme: matches.flag(&STATS_README),
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                time: args.contains("--time"),
+            "dashboard" => AppArguments::Dashboard,
+            "completions" => AppArguments::Completions {
+                shell: matches.required(&SHELL)?,
             },
-            Some(x) => {
-                eprintln!("Unknown command: {x}");
-                process::exit(1);
-            }
-            None => {
-                eprintln!("No command specified.");
-                process::exit(1);
-            }
+            name => unreachable!("command \"{name}\" is declared but not handled"),
         };
 
-        let remaining = args.finish();
-        if !remaining.is_empty() {
-            eprintln!("Warning: unknown argument(s): {remaining:?}.");
+        Ok(app_args)
+    }
+
+    /// Parse the layout of the readme benchmark table, e.g. `--columns part1,part2,total --sort slowest --mark-slowest 3`.
+    fn parse_table_options(matches: &Matches) -> Result<TableOptions, Error> {
+        let mut options = TableOptions::default();
+
+        if let Some(columns) = matches.value::<ColumnList>(&COLUMNS)? {
+            options.columns = columns.0;
         }
 
-        Ok(app_args)
+        if let Some(sort) = matches.value(&SORT)? {
+            options.sort = sort;
+        }
+
+        if let Some(mark_slowest) = matches.value(&MARK_SLOWEST)? {
+            options.mark_slowest = mark_slowest;
+        }
+
+        Ok(options)
+    }
+
+    /// Parse the tag filter, e.g. `--tag grid,dp`.
+    fn parse_tags(matches: &Matches) -> Result<Vec<String>, Error> {
+        Ok(matches
+            .value::<String>(&TAG)?
+            .map(|s| tags::parse_filter(&s))
+            .unwrap_or_default())
+    }
+
+    /// Comma separated list of table columns, e.g. `part1,part2,total`.
+    struct ColumnList(Vec<Column>);
+
+    impl FromStr for ColumnList {
+        type Err = readme_benchmarks::Error;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            s.split(',')
+                .map(str::parse)
+                .collect::<Result<_, _>>()
+                .map(Self)
+        }
     }
 }
 
 fn main() {
-    match parse() {
-        Err(err) => {
-            eprintln!("Error: {err}");
-            std::process::exit(1);
-        }
-        Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day } => scaffold::handle(day),
-            AppArguments::Solve {
-                day,
-                release,
-                time,
-                submit,
-            } => solve::handle(day, release, time, submit),
-        },
+    let args = parse().unwrap_or_else(|e| e.exit());
+
+    match args {
+        AppArguments::All {
+            release,
+            time,
+            table_options,
+            tags,
+        } => all::handle(release, time, &table_options, &tags),
+        AppArguments::Await => countdown::handle(),
+        AppArguments::BenchReport { tags } => bench_report::handle(&tags),
+        AppArguments::Download { day } => download::handle(day),
+        AppArguments::Inputs { action, key_file } => inputs::handle(action, key_file.as_deref()),
+        AppArguments::Read { day, part } => read::handle(day, part),
+        AppArguments::Scaffold { day } => scaffold::handle(day),
+        AppArguments::Solve {
+            day,
+            release,
+            solution_args,
+        } => solve::handle(day, release, solution_args),
+        AppArguments::Search { terms, tags } => search::handle(&terms, &tags),
+        AppArguments::WatchDay { day, release } => watch::handle(day, release),
+        AppArguments::Progress { readme } => progress::handle(readme),
+        AppArguments::Stats { readme } => stats::handle(readme),
+        AppArguments::Dashboard => dashboard::handle(),
+        AppArguments::Completions { shell } => completions::handle(shell),
     };
 }
@@ -1,9 +1,15 @@
 /// Wrapper module around the "aoc-cli" command-line.
 use std::{
     fmt::Display,
+    path::Path,
     process::{Command, Output, Stdio},
 };
 
+use crate::template::{
+    config::{self, Config},
+    input,
+    solve_log::{self, Entry, Event},
+};
 use crate::Day;
 
 #[derive(Debug)]
@@ -28,7 +34,7 @@ impl Display for AocCommandError {
 }
 
 pub fn check() -> Result<(), AocCommandError> {
-    Command::new("aoc")
+    command(config::get())
         .arg("-V")
         .output()
         .map_err(|_| AocCommandError::CommandNotFound)?;
@@ -36,9 +42,11 @@ pub fn check() -> Result<(), AocCommandError> {
 }
 
 pub fn read(day: Day) -> Result<Output, AocCommandError> {
-    let puzzle_path = get_puzzle_path(day);
+    let config = config::get();
+    let puzzle_path = get_puzzle_path(config, day);
 
     let args = build_args(
+        config,
         "read",
         &[
             "--description-only".into(),
@@ -48,14 +56,20 @@ pub fn read(day: Day) -> Result<Output, AocCommandError> {
         day,
     );
 
-    call_aoc_cli(&args)
+    call_aoc_cli(config, &args)
 }
 
 pub fn download(day: Day) -> Result<Output, AocCommandError> {
-    let input_path = get_input_path(day);
-    let puzzle_path = get_puzzle_path(day);
+    download_with(config::get(), day)
+}
+
+/// Same as [`download`], with the command and paths of another config.
+pub fn download_with(config: &Config, day: Day) -> Result<Output, AocCommandError> {
+    let input_path = get_input_path(config, day);
+    let puzzle_path = get_puzzle_path(config, day);
 
     let args = build_args(
+        config,
         "download",
         &[
             "--overwrite".into(),
@@ -67,40 +81,79 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
         day,
     );
 
-    let output = call_aoc_cli(&args)?;
+    let output = call_aoc_cli(config, &args)?;
+    input::record_checksum(Path::new(&input_path)).map_err(|_| AocCommandError::IoError)?;
     println!("---");
     println!("🎄 Successfully wrote input to \"{}\".", &input_path);
     println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+
+    if let Err(e) = solve_log::append_to(&config.paths.solve_log, Entry::now(day, Event::Opened)) {
+        eprintln!("Failed to record the open time: {e}");
+    }
+
     Ok(output)
 }
 
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
+/// Answer of the advent of code website to a submission.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubmitVerdict {
+    Correct,
+    Incorrect,
+    TooRecent,
+    AlreadySolved,
+    Unknown,
+}
+
+impl SubmitVerdict {
+    #[must_use]
+    pub fn from_response(response: &str) -> Self {
+        if response.contains("That's the right answer") {
+            SubmitVerdict::Correct
+        } else if response.contains("That's not the right answer") {
+            SubmitVerdict::Incorrect
+        } else if response.contains("You gave an answer too recently") {
+            SubmitVerdict::TooRecent
+        } else if response.contains("You don't seem to be solving the right level") {
+            SubmitVerdict::AlreadySolved
+        } else {
+            SubmitVerdict::Unknown
+        }
+    }
+}
+
+pub fn submit(day: Day, part: u8, result: &str) -> Result<SubmitVerdict, AocCommandError> {
     // workaround: the argument order is inverted for submit.
-    let mut args = build_args("submit", &[], day);
+    let config = config::get();
+    let mut args = build_args(config, "submit", &[], day);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
+    let output = call_aoc_cli_captured(config, &args)?;
+
+    let response = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    Ok(SubmitVerdict::from_response(&response))
 }
 
-fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
+fn get_input_path(config: &Config, day: Day) -> String {
+    config.input_path(day).display().to_string()
 }
 
-fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
+fn get_puzzle_path(config: &Config, day: Day) -> String {
+    config.puzzle_path(day).display().to_string()
 }
 
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
-    }
+#[must_use]
+pub fn get_year() -> Option<u16> {
+    config::get().year
 }
 
-fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
+fn build_args(config: &Config, command: &str, args: &[String], day: Day) -> Vec<String> {
     let mut cmd_args = args.to_vec();
 
-    if let Some(year) = get_year() {
+    if let Some(year) = config.year {
         cmd_args.push("--year".into());
         cmd_args.push(year.to_string());
     }
@@ -110,9 +163,17 @@ fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
     cmd_args
 }
 
-fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
+/// The configured aoc-cli command, with the arguments it was configured with.
+fn command(config: &Config) -> Command {
+    let mut parts = config.aoc_cli.split_whitespace();
+    let mut command = Command::new(parts.next().unwrap_or("aoc"));
+    command.args(parts);
+    command
+}
+
+fn call_aoc_cli(config: &Config, args: &[String]) -> Result<Output, AocCommandError> {
     // println!("Calling >aoc with: {}", args.join(" "));
-    let output = Command::new("aoc")
+    let output = command(config)
         .args(args)
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
@@ -125,3 +186,22 @@ fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
         Err(AocCommandError::BadExitStatus(output))
     }
 }
+
+/// Same as [`call_aoc_cli`], but keeps the output around for inspection after echoing it.
+fn call_aoc_cli_captured(config: &Config, args: &[String]) -> Result<Output, AocCommandError> {
+    let output = command(config)
+        .args(args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|_| AocCommandError::CommandNotCallable)?;
+
+    print!("{}", String::from_utf8_lossy(&output.stdout));
+    eprint!("{}", String::from_utf8_lossy(&output.stderr));
+
+    if output.status.success() {
+        Ok(output)
+    } else {
+        Err(AocCommandError::BadExitStatus(output))
+    }
+}
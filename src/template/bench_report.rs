@@ -0,0 +1,605 @@
+/// HTML report of the bench samples of every part, written by `bench-report`.
+/// It shows the distribution of the samples as histogram and violin plot and compares each part to the previous run.
+/// The report is a single file with inline styles and SVG plots, so it works offline.
+use std::{
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::template::{
+    readme_chart::{escape, Svg},
+    runner::SAMPLES_LABEL,
+};
+use crate::Day;
+
+pub const REPORT_DIR: &str = "target/aoc-report";
+const REPORT_FILE: &str = "index.html";
+/// Samples of the last run, the baseline of the next report.
+const LAST_RUN_FILE: &str = "last-run.txt";
+
+/// Changes of the mean below this fraction are reported as noise, same as the default of criterion.
+const NOISE_THRESHOLD: f64 = 0.02;
+/// Z-score of a two-sided 95% confidence interval.
+const Z_95: f64 = 1.96;
+
+const PLOT_WIDTH: f64 = 460.0;
+const HISTOGRAM_HEIGHT: f64 = 220.0;
+const VIOLIN_ROW_HEIGHT: f64 = 60.0;
+const MARGIN_LEFT: f64 = 70.0;
+const MARGIN_RIGHT: f64 = 20.0;
+const MARGIN_TOP: f64 = 20.0;
+const MARGIN_BOTTOM: f64 = 40.0;
+const MAX_BINS: usize = 50;
+/// Points at which the density of a violin is estimated.
+const DENSITY_POINTS: usize = 100;
+
+const COLOR_CURRENT: &str = "#4e79a7";
+const COLOR_PREVIOUS: &str = "#bab0ac";
+const COLOR_MEAN: &str = "#e15759";
+const COLOR_AXIS: &str = "#888888";
+
+/// Samples of one part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Series {
+    pub day: Day,
+    pub part: u8,
+    /// Time of each sample in nanoseconds.
+    pub samples: Vec<u64>,
+}
+
+impl Series {
+    fn id(&self) -> String {
+        format!("day-{}-part-{}", self.day, self.part)
+    }
+}
+
+/// Samples of all parts that were benched in one run.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Run {
+    pub series: Vec<Series>,
+}
+
+impl Run {
+    /// The run saved by the last report, `None` if there is none yet.
+    pub fn load_last() -> Result<Option<Self>, io::Error> {
+        match fs::read_to_string(last_run_path()) {
+            Ok(s) => Ok(Some(Self::parse(&s))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Each line holds `<day> <part> <nanoseconds>...`, lines that can't be parsed are skipped.
+    pub(crate) fn parse(s: &str) -> Self {
+        let series = s
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.split_whitespace();
+                let day = fields.next()?.parse().ok()?;
+                let part = fields.next()?.parse().ok()?;
+                let samples = fields.map(str::parse).collect::<Result<_, _>>().ok()?;
+                Some(Series { day, part, samples })
+            })
+            .collect();
+
+        Self { series }
+    }
+
+    /// Add the samples printed by a solution binary run with `--samples`.
+    pub fn add_output(&mut self, day: Day, output: &[String]) {
+        for line in output {
+            if let Some((part, samples)) = parse_samples(line) {
+                self.series.push(Series { day, part, samples });
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn get(&self, day: Day, part: u8) -> Option<&Series> {
+        self.series
+            .iter()
+            .find(|series| series.day == day && series.part == part)
+    }
+
+    /// Replace the parts benched in `other`, keep the others.
+    /// A run limited to some tags then does not discard the baseline of the other days.
+    pub fn merge(&mut self, other: &Run) {
+        self.series
+            .retain(|series| other.get(series.day, series.part).is_none());
+        self.series.extend(other.series.iter().cloned());
+        self.series.sort_by_key(|series| (series.day, series.part));
+    }
+}
+
+impl Display for Run {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for series in &self.series {
+            write!(f, "{} {}", series.day, series.part)?;
+            for sample in &series.samples {
+                write!(f, " {sample}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse a line like `Part 1 samples (ns): 1200 1150`.
+fn parse_samples(line: &str) -> Option<(u8, Vec<u64>)> {
+    let (label, samples) = line.split_once(SAMPLES_LABEL)?;
+    let part = label.trim().strip_prefix("Part ")?.parse().ok()?;
+    let samples = samples
+        .split_whitespace()
+        .map(str::parse)
+        .collect::<Result<_, _>>()
+        .ok()?;
+    Some((part, samples))
+}
+
+fn last_run_path() -> PathBuf {
+    Path::new(REPORT_DIR).join(LAST_RUN_FILE)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub mean: f64,
+    pub median: f64,
+    pub std_dev: f64,
+    pub min: f64,
+    pub max: f64,
+    /// Samples outside the Tukey fences, 1.5 times the interquartile range beyond the quartiles.
+    pub outliers: usize,
+}
+
+impl Stats {
+    /// Stats of a series of samples, `None` if it is empty.
+    #[must_use]
+    pub fn new(samples: &[u64]) -> Option<Self> {
+        #[allow(clippy::cast_precision_loss)]
+        let mut sorted: Vec<f64> = samples.iter().map(|&s| s as f64).collect();
+        if sorted.is_empty() {
+            return None;
+        }
+        sorted.sort_by(f64::total_cmp);
+
+        #[allow(clippy::cast_precision_loss)]
+        let n = sorted.len() as f64;
+        let mean = sorted.iter().sum::<f64>() / n;
+        let variance = if sorted.len() > 1 {
+            sorted.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1.0)
+        } else {
+            0.0
+        };
+
+        let (q1, q3) = (percentile(&sorted, 0.25), percentile(&sorted, 0.75));
+        let fence = 1.5 * (q3 - q1);
+        let outliers = sorted
+            .iter()
+            .filter(|&&s| s < q1 - fence || s > q3 + fence)
+            .count();
+
+        Some(Self {
+            samples: sorted.len(),
+            mean,
+            median: percentile(&sorted, 0.5),
+            std_dev: variance.sqrt(),
+            min: sorted[0],
+            max: sorted[sorted.len() - 1],
+            outliers,
+        })
+    }
+
+    fn std_error(&self) -> f64 {
+        #[allow(clippy::cast_precision_loss)]
+        let n = self.samples as f64;
+        self.std_dev / n.sqrt()
+    }
+}
+
+/// Percentile of sorted values, interpolating between neighbouring values.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    #[allow(clippy::cast_precision_loss)]
+    let rank = p * (sorted.len() - 1) as f64;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - rank.floor())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Improved,
+    Regressed,
+    NoChange,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Comparison {
+    /// Relative change of the mean, e.g. `0.1` if the part got 10% slower.
+    pub change: f64,
+    pub verdict: Verdict,
+}
+
+/// Compare a part to the previous run.
+/// A change counts if it exceeds the noise threshold and the 95% confidence interval of the difference of the means.
+#[must_use]
+pub fn compare(current: &Stats, previous: &Stats) -> Comparison {
+    let difference = current.mean - previous.mean;
+    let change = difference / previous.mean.max(f64::EPSILON);
+    let interval = Z_95 * current.std_error().hypot(previous.std_error());
+
+    let verdict = if change.abs() <= NOISE_THRESHOLD || difference.abs() <= interval {
+        Verdict::NoChange
+    } else if difference < 0.0 {
+        Verdict::Improved
+    } else {
+        Verdict::Regressed
+    };
+
+    Comparison { change, verdict }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn format_nanos(nanos: f64) -> String {
+    format!("{:.1?}", Duration::from_nanos(nanos.round() as u64))
+}
+
+/// Maps nanoseconds to horizontal positions of a plot.
+struct LinearScale {
+    min: f64,
+    max: f64,
+}
+
+impl LinearScale {
+    fn new(min: f64, max: f64) -> Self {
+        // widen a range without spread, so a single value ends up in the middle.
+        let padding = if max > min { 0.0 } else { min.max(1.0) * 0.01 };
+        Self {
+            min: min - padding,
+            max: max + padding,
+        }
+    }
+
+    fn x(&self, nanos: f64) -> f64 {
+        MARGIN_LEFT
+            + (nanos - self.min) / (self.max - self.min) * (PLOT_WIDTH - MARGIN_LEFT - MARGIN_RIGHT)
+    }
+
+    /// Labels at both ends and in the middle of the axis.
+    fn draw_axis(&self, svg: &mut Svg, y: f64) {
+        svg.line(MARGIN_LEFT, y, PLOT_WIDTH - MARGIN_RIGHT, y, COLOR_AXIS);
+        for nanos in [self.min, (self.min + self.max) / 2.0, self.max] {
+            let x = self.x(nanos);
+            svg.line(x, y, x, y + 4.0, COLOR_AXIS);
+            svg.text(x, y + 18.0, "middle", &format_nanos(nanos));
+        }
+    }
+}
+
+/// Histogram of the samples with a marker at the mean.
+fn histogram(samples: &[u64], stats: &Stats) -> String {
+    let scale = LinearScale::new(stats.min, stats.max);
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let bins = ((samples.len() as f64).sqrt().ceil() as usize).clamp(1, MAX_BINS);
+    #[allow(clippy::cast_precision_loss)]
+    let bin_width = (scale.max - scale.min) / bins as f64;
+
+    let mut counts = vec![0_usize; bins];
+    for &sample in samples {
+        #[allow(clippy::cast_precision_loss)]
+        let offset = (sample as f64 - scale.min) / bin_width;
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let bin = (offset as usize).min(bins - 1);
+        counts[bin] += 1;
+    }
+
+    let max_count = counts.iter().copied().max().unwrap_or(1);
+    let axis_y = HISTOGRAM_HEIGHT - MARGIN_BOTTOM;
+    let plot_height = axis_y - MARGIN_TOP;
+    let mut svg = Svg::new(PLOT_WIDTH, HISTOGRAM_HEIGHT);
+
+    for (bin, &count) in counts.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let (start, height) = (
+            scale.min + bin_width * bin as f64,
+            plot_height * count as f64 / max_count as f64,
+        );
+        let (x1, x2) = (scale.x(start), scale.x(start + bin_width));
+        svg.rect(
+            x1,
+            axis_y - height,
+            (x2 - x1 - 1.0).max(1.0),
+            height,
+            COLOR_CURRENT,
+        );
+    }
+
+    let mean_x = scale.x(stats.mean);
+    svg.line(mean_x, MARGIN_TOP, mean_x, axis_y, COLOR_MEAN);
+    svg.text(mean_x, MARGIN_TOP - 6.0, "middle", "mean");
+    svg.text(
+        MARGIN_LEFT - 8.0,
+        MARGIN_TOP + 10.0,
+        "end",
+        &max_count.to_string(),
+    );
+    svg.text(MARGIN_LEFT - 8.0, axis_y, "end", "0");
+    scale.draw_axis(&mut svg, axis_y);
+
+    svg.to_string()
+}
+
+/// Gaussian kernel density estimate of the samples at each point, with the bandwidth of Silverman's rule of thumb.
+fn density(samples: &[u64], stats: &Stats, points: &[f64]) -> Vec<f64> {
+    #[allow(clippy::cast_precision_loss)]
+    let n = samples.len() as f64;
+    let bandwidth = 1.06 * stats.std_dev * n.powf(-0.2);
+    let bandwidth = if bandwidth > 0.0 {
+        bandwidth
+    } else {
+        stats.mean.max(1.0) * 0.01
+    };
+
+    points
+        .iter()
+        .map(|&x| {
+            samples
+                .iter()
+                .map(|&s| {
+                    #[allow(clippy::cast_precision_loss)]
+                    let z = (x - s as f64) / bandwidth;
+                    (-0.5 * z * z).exp()
+                })
+                .sum()
+        })
+        .collect()
+}
+
+/// Violin plot of the current samples, above the samples of the previous run if there are any.
+fn violin(current: (&[u64], &Stats), previous: Option<(&[u64], &Stats)>) -> String {
+    let rows: Vec<(&str, &[u64], &Stats, &str)> = [
+        Some(("current", current.0, current.1, COLOR_CURRENT)),
+        previous.map(|(samples, stats)| ("previous", samples, stats, COLOR_PREVIOUS)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    let min = rows
+        .iter()
+        .map(|row| row.2.min)
+        .fold(f64::INFINITY, f64::min);
+    let max = rows
+        .iter()
+        .map(|row| row.2.max)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let scale = LinearScale::new(min, max);
+    let points: Vec<f64> = (0..DENSITY_POINTS)
+        .map(|i| {
+            #[allow(clippy::cast_precision_loss)]
+            let t = i as f64 / (DENSITY_POINTS - 1) as f64;
+            scale.min + (scale.max - scale.min) * t
+        })
+        .collect();
+
+    #[allow(clippy::cast_precision_loss)]
+    let axis_y = MARGIN_TOP + VIOLIN_ROW_HEIGHT * rows.len() as f64;
+    let mut svg = Svg::new(PLOT_WIDTH, axis_y + MARGIN_BOTTOM);
+
+    for (row, (label, samples, stats, color)) in rows.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let center = MARGIN_TOP + VIOLIN_ROW_HEIGHT * (row as f64 + 0.5);
+        let densities = density(samples, stats, &points);
+        let max_density = densities.iter().copied().fold(f64::EPSILON, f64::max);
+        let half_height = |d: f64| d / max_density * (VIOLIN_ROW_HEIGHT / 2.0 - 4.0);
+
+        // upper outline from left to right, then the mirrored lower outline back.
+        let outline: Vec<(f64, f64)> = points
+            .iter()
+            .zip(&densities)
+            .map(|(&x, &d)| (scale.x(x), center - half_height(d)))
+            .chain(
+                points
+                    .iter()
+                    .zip(&densities)
+                    .rev()
+                    .map(|(&x, &d)| (scale.x(x), center + half_height(d))),
+            )
+            .collect();
+        svg.polygon(&outline, color);
+
+        let median_x = scale.x(stats.median);
+        svg.line(median_x, center - 8.0, median_x, center + 8.0, COLOR_MEAN);
+        svg.text(MARGIN_LEFT - 8.0, center + 4.0, "end", label);
+    }
+
+    scale.draw_axis(&mut svg, axis_y);
+    svg.to_string()
+}
+
+const STYLE: &str = "
+body { font-family: sans-serif; margin: 2em auto; max-width: 1000px; color: #222; }
+table { border-collapse: collapse; margin: 1em 0; }
+th, td { padding: 4px 10px; text-align: right; border-bottom: 1px solid #ddd; }
+th:first-child, td:first-child { text-align: left; }
+section { margin-top: 2.5em; }
+.plots { display: flex; flex-wrap: wrap; gap: 1em; }
+.improved { color: #2e7d32; }
+.regressed { color: #c62828; }
+.no-change { color: #888; }
+";
+
+/// Table cell with the change to the previous run.
+fn change_cell(comparison: Option<Comparison>) -> String {
+    let Some(Comparison { change, verdict }) = comparison else {
+        return "<td>-</td>".into();
+    };
+
+    let (class, label) = match verdict {
+        Verdict::Improved => ("improved", "improved"),
+        Verdict::Regressed => ("regressed", "regressed"),
+        Verdict::NoChange => ("no-change", "no change"),
+    };
+    format!(
+        r#"<td class="{class}">{:+.2}% ({label})</td>"#,
+        change * 100.0
+    )
+}
+
+/// Render the report of a run, comparing it to the previous run if there is one.
+#[must_use]
+pub fn render(run: &Run, previous: Option<&Run>) -> String {
+    let mut rows = String::new();
+    let mut sections = String::new();
+
+    for series in &run.series {
+        let Some(stats) = Stats::new(&series.samples) else {
+            continue;
+        };
+
+        let baseline = previous
+            .and_then(|previous| previous.get(series.day, series.part))
+            .and_then(|previous| Some((&previous.samples[..], Stats::new(&previous.samples)?)));
+        let comparison = baseline.map(|(_, previous_stats)| compare(&stats, &previous_stats));
+
+        let stat_cells = format!(
+            "<td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td>",
+            stats.samples,
+            format_nanos(stats.mean),
+            format_nanos(stats.median),
+            format_nanos(stats.std_dev),
+            stats.outliers,
+        );
+        let id = series.id();
+        let title = escape(&format!("Day {} · Part {}", series.day, series.part));
+
+        rows.push_str(&format!(
+            "<tr><td><a href=\"#{id}\">{title}</a></td>{stat_cells}{}</tr>\n",
+            change_cell(comparison)
+        ));
+
+        let previous_stats = baseline.as_ref().map(|(samples, stats)| (*samples, stats));
+        sections.push_str(&format!(
+            "<section id=\"{id}\">\n<h2>{title}</h2>\n<div class=\"plots\">\n{}{}</div>\n</section>\n",
+            histogram(&series.samples, &stats),
+            violin((&series.samples, &stats), previous_stats),
+        ));
+    }
+
+    let baseline_note = if previous.is_some() {
+        "Changes are relative to the previous run, shown in grey in the violin plots."
+    } else {
+        "There is no previous run to compare against yet."
+    };
+
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>Benchmark report</title>\n<style>{STYLE}</style>\n</head>\n<body>\n<h1>🎄 Benchmark report</h1>\n<p>{baseline_note} The red marks show the mean in histograms and the median in violin plots.</p>\n<table>\n<tr><th>Part</th><th>Samples</th><th>Mean</th><th>Median</th><th>Std. dev.</th><th>Outliers</th><th>Change</th></tr>\n{rows}</table>\n{sections}</body>\n</html>\n"
+    )
+}
+
+/// Write the report to [`REPORT_DIR`] and save the run as baseline of the next report.
+/// Returns the path of the report.
+pub fn write(run: &Run, previous: Option<&Run>) -> Result<PathBuf, io::Error> {
+    fs::create_dir_all(REPORT_DIR)?;
+
+    let path = Path::new(REPORT_DIR).join(REPORT_FILE);
+    fs::write(&path, render(run, previous))?;
+
+    let mut baseline = previous.cloned().unwrap_or_default();
+    baseline.merge(run);
+    fs::write(last_run_path(), baseline.to_string())?;
+
+    Ok(path)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{compare, parse_samples, render, Run, Series, Stats, Verdict};
+    use crate::day;
+
+    fn series(part: u8, samples: &[u64]) -> Series {
+        Series {
+            day: day!(3),
+            part,
+            samples: samples.to_vec(),
+        }
+    }
+
+    #[test]
+    fn computes_stats() {
+        let stats = Stats::new(&[10, 12, 11, 13, 100]).unwrap();
+        assert_eq!(stats.samples, 5);
+        assert!((stats.mean - 29.2).abs() < 1e-9);
+        assert!((stats.median - 12.0).abs() < 1e-9);
+        assert!((stats.min - 10.0).abs() < 1e-9);
+        assert_eq!(stats.outliers, 1);
+        assert!(Stats::new(&[]).is_none());
+    }
+
+    #[test]
+    fn detects_regressions() {
+        let base: Vec<u64> = (0..100).map(|i| 1000 + i % 10).collect();
+        let slower: Vec<u64> = base.iter().map(|s| s * 12 / 10).collect();
+        let noisy: Vec<u64> = base.iter().map(|s| s + 10).collect();
+        let stats = |samples: &[u64]| Stats::new(samples).unwrap();
+
+        let regressed = compare(&stats(&slower), &stats(&base));
+        assert_eq!(regressed.verdict, Verdict::Regressed);
+        assert!((regressed.change - 0.2).abs() < 0.01);
+        assert_eq!(
+            compare(&stats(&base), &stats(&slower)).verdict,
+            Verdict::Improved
+        );
+        // 1% is below the noise threshold.
+        assert_eq!(
+            compare(&stats(&noisy), &stats(&base)).verdict,
+            Verdict::NoChange
+        );
+    }
+
+    #[test]
+    fn roundtrips_runs() {
+        let mut run = Run::default();
+        run.add_output(
+            day!(3),
+            &[
+                "Part 1: 42 (1.2µs @ 3 samples)".into(),
+                "Part 1 samples (ns): 1200 1100 1300".into(),
+                "Part 2 samples (ns): 5 x".into(),
+            ],
+        );
+        assert_eq!(run.series, vec![series(1, &[1200, 1100, 1300])]);
+        assert_eq!(run.to_string(), "03 1 1200 1100 1300\n");
+        assert_eq!(Run::parse(&run.to_string()), run);
+        assert_eq!(parse_samples("Part 2 samples (ns): 7"), Some((2, vec![7])));
+
+        let mut baseline = Run {
+            series: vec![series(1, &[1]), series(2, &[2])],
+        };
+        baseline.merge(&run);
+        assert_eq!(baseline.series[0], series(1, &[1200, 1100, 1300]));
+        assert_eq!(baseline.series[1], series(2, &[2]));
+    }
+
+    #[test]
+    fn renders_self_contained_report() {
+        let run = Run {
+            series: vec![series(1, &[1200, 1100, 1300, 1250]), series(2, &[50])],
+        };
+        let previous = Run {
+            series: vec![series(1, &[1000, 1010, 990, 1005])],
+        };
+
+        let html = render(&run, Some(&previous));
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("id=\"day-03-part-1\""));
+        assert!(html.contains("regressed"));
+        // two histograms and two violin plots.
+        assert_eq!(html.matches("<svg").count(), 4);
+        assert_eq!(html.matches("<polygon").count(), 3);
+        // no scripts, stylesheets or images are loaded.
+        assert!(!html.contains("<script") && !html.contains("<link") && !html.contains("src="));
+    }
+}
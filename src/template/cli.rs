@@ -0,0 +1,1003 @@
+/// Declarative model of the command line of the template and the solution binaries.
+/// Commands are described once and used for parsing, help texts, usage errors and shell completions.
+use std::{collections::HashMap, fmt, str::FromStr, sync::OnceLock};
+
+use crate::template::ANSI_BOLD;
+use crate::template::ANSI_RESET;
+
+pub const BIN_NAME: &str = "advent_of_code";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArgKind {
+    /// A `--name` switch without a value.
+    Flag,
+    /// A `--name <value>` option.
+    Option,
+    /// A required value at a fixed position.
+    Positional,
+    /// Any number of values after the positional arguments.
+    Rest,
+}
+
+#[derive(Debug)]
+pub struct Arg {
+    pub name: &'static str,
+    pub kind: ArgKind,
+    pub value_name: &'static str,
+    pub help: &'static str,
+    /// Accepted values, offered by shell completions.
+    pub values: &'static [&'static str],
+}
+
+impl Arg {
+    const fn flag(name: &'static str, help: &'static str) -> Self {
+        Self {
+            name,
+            kind: ArgKind::Flag,
+            value_name: "",
+            help,
+            values: &[],
+        }
+    }
+
+    const fn option(name: &'static str, value_name: &'static str, help: &'static str) -> Self {
+        Self {
+            name,
+            kind: ArgKind::Option,
+            value_name,
+            help,
+            values: &[],
+        }
+    }
+
+    const fn positional(name: &'static str, help: &'static str) -> Self {
+        Self {
+            name,
+            kind: ArgKind::Positional,
+            value_name: name,
+            help,
+            values: &[],
+        }
+    }
+
+    const fn rest(name: &'static str, help: &'static str) -> Self {
+        Self {
+            name,
+            kind: ArgKind::Rest,
+            value_name: name,
+            help,
+            values: &[],
+        }
+    }
+
+    const fn values(self, values: &'static [&'static str]) -> Self {
+        Self { values, ..self }
+    }
+
+    fn usage(&self) -> String {
+        match self.kind {
+            ArgKind::Flag => format!("[--{}]", self.name),
+            ArgKind::Option => format!("[--{} <{}>]", self.name, self.value_name),
+            ArgKind::Positional => format!("<{}>", self.name),
+            ArgKind::Rest => format!("[<{}>...]", self.name),
+        }
+    }
+
+    fn label(&self) -> String {
+        match self.kind {
+            ArgKind::Flag => format!("--{}", self.name),
+            ArgKind::Option => format!("--{} <{}>", self.name, self.value_name),
+            ArgKind::Positional => format!("<{}>", self.name),
+            ArgKind::Rest => format!("<{}>...", self.name),
+        }
+    }
+
+    fn is_named(&self) -> bool {
+        matches!(self.kind, ArgKind::Flag | ArgKind::Option)
+    }
+}
+
+#[derive(Debug)]
+pub struct Command {
+    pub name: &'static str,
+    pub about: &'static str,
+    pub args: &'static [&'static Arg],
+}
+
+const DAY_VALUES: &[&str] = &[
+    "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "17",
+    "18", "19", "20", "21", "22", "23", "24", "25",
+];
+
+pub static DAY: Arg = Arg::positional("day", "Day of the puzzle, 1 to 25.").values(DAY_VALUES);
+pub static RELEASE: Arg = Arg::flag("release", "Use an optimized build.");
+pub static TIME: Arg = Arg::flag("time", "Bench the solution and print the average time.");
+pub static SUBMIT: Arg =
+    Arg::option("submit", "part", "Submit the answer of a part via aoc-cli.").values(&["1", "2"]);
+pub static PART: Arg =
+    Arg::option("part", "part", "Only show the description of a part.").values(&["1", "2"]);
+pub static VARIANTS: Arg = Arg::flag("variants", "Run and compare all registered variants.");
+pub static SAMPLES: Arg = Arg::flag(
+    "samples",
+    "Print the time of every bench sample, used by `bench-report`.",
+);
+pub static COLUMNS: Arg = Arg::option(
+    "columns",
+    "columns",
+    "Comma separated columns of the benchmark table.",
+)
+.values(&[
+    "parse", "part1", "part2", "total", "memory", "samples", "notes",
+]);
+pub static SORT: Arg = Arg::option("sort", "order", "Order of the benchmark table rows.")
+    .values(&["day", "slowest", "fastest"]);
+pub static MARK_SLOWEST: Arg = Arg::option(
+    "mark-slowest",
+    "n",
+    "Mark the n slowest days in the benchmark table.",
+);
+pub static YEAR: Arg = Arg::option("year", "year", "Year of the event, overrides aoc.toml.");
+pub static README: Arg = Arg::flag("readme", "Update the readme with the progress.");
+pub static STATS_README: Arg = Arg::flag("readme", "Update the readme with the solve times.");
+pub static TAG: Arg = Arg::option(
+    "tag",
+    "tags",
+    "Comma separated tags from data/tags.txt, only days with all of them are included.",
+);
+pub static TERMS: Arg = Arg::rest("terms", "Words to search for, all of them must match.");
+pub static INPUTS_ACTION: Arg = Arg::positional(
+    "action",
+    "Encrypt the inputs for committing, or decrypt the committed copies.",
+)
+.values(&["encrypt", "decrypt"]);
+pub static KEY_FILE: Arg = Arg::option(
+    "key-file",
+    "path",
+    "Derive the key from this file instead of a passphrase.",
+);
+pub static SHELL: Arg =
+    Arg::positional("shell", "Shell to generate completions for.").values(&["bash", "zsh", "fish"]);
+
+/// Arguments understood by the solution binaries, forwarded by `solve`.
+pub static SOLUTION_ARGS: &[&Arg] = &[&TIME, &SUBMIT, &VARIANTS, &SAMPLES];
+
+pub static COMMANDS: &[Command] = &[
+    Command {
+        name: "await",
+        about: "Count down to the next puzzle unlock, then download and scaffold the day.",
+        args: &[&YEAR],
+    },
+    Command {
+        name: "scaffold",
+        about: "Create the solution, input and example files for a day.",
+        args: &[&DAY],
+    },
+    Command {
+        name: "download",
+        about: "Download the input and puzzle description for a day via aoc-cli.",
+        args: &[&DAY, &YEAR],
+    },
+    Command {
+        name: "read",
+        about: "Read the puzzle description for a day in the terminal.",
+        args: &[&DAY, &PART, &YEAR],
+    },
+    Command {
+        name: "inputs",
+        about: "Encrypt or decrypt the puzzle inputs, so they can be committed privately.",
+        args: &[&INPUTS_ACTION, &KEY_FILE],
+    },
+    Command {
+        name: "solve",
+        about: "Run the solution for a day against the real input.",
+        args: &[&DAY, &RELEASE, &TIME, &SUBMIT, &VARIANTS, &SAMPLES, &YEAR],
+    },
+    Command {
+        name: "all",
+        about: "Run the solutions for all days.",
+        args: &[&RELEASE, &TIME, &COLUMNS, &SORT, &MARK_SLOWEST, &TAG],
+    },
+    Command {
+        name: "time",
+        about:
+            "Bench all solutions and update the readme benchmarks, same as `all --release --time`.",
+        args: &[&COLUMNS, &SORT, &MARK_SLOWEST],
+    },
+    Command {
+        name: "bench-report",
+        about: "Bench all solutions and write an HTML report of the timings to target/aoc-report.",
+        args: &[&TAG],
+    },
+    Command {
+        name: "watch-day",
+        about: "Re-run the examples and input of a day whenever its files change.",
+        args: &[&DAY, &RELEASE],
+    },
+    Command {
+        name: "progress",
+        about: "Print the locally tracked ⭐ progress.",
+        args: &[&README, &YEAR],
+    },
+    Command {
+        name: "stats",
+        about: "Print how long each part took to solve, tracked from download to submit.",
+        args: &[&STATS_README],
+    },
+    Command {
+        name: "search",
+        about: "Search the puzzle descriptions and solutions of all days.",
+        args: &[&TERMS, &TAG],
+    },
+    Command {
+        name: "dashboard",
+        about: "Show the status of all days and run them interactively.",
+        args: &[],
+    },
+    Command {
+        name: "completions",
+        about: "Print a completion script for bash, zsh or fish.",
+        args: &[&SHELL],
+    },
+];
+
+/// Reasons parsing stops before a command can run.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `--help` was requested, holds the help text.
+    Help(String),
+    /// The arguments don't match the command, holds the message and the usage line.
+    Usage { message: String, usage: String },
+}
+
+impl Error {
+    /// Exit code following the convention of common argument parsers.
+    #[must_use]
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::Help(_) => 0,
+            Error::Usage { .. } => 2,
+        }
+    }
+
+    /// Print the error and exit the process.
+    pub fn exit(&self) -> ! {
+        match self {
+            Error::Help(_) => println!("{self}"),
+            Error::Usage { .. } => eprintln!("{self}"),
+        }
+        std::process::exit(self.exit_code());
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Help(help) => write!(f, "{help}"),
+            Error::Usage { message, usage } => write!(
+                f,
+                "error: {message}\n\nUsage: {usage}\n\nFor more information, try '--help'."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Parsed arguments of a command.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Matches {
+    values: HashMap<&'static str, String>,
+    rest: Vec<String>,
+    usage: String,
+}
+
+impl Matches {
+    #[must_use]
+    pub fn flag(&self, arg: &Arg) -> bool {
+        self.values.contains_key(arg.name)
+    }
+
+    /// Value of an option or positional argument, `None` if the option was not passed.
+    pub fn value<T>(&self, arg: &Arg) -> Result<Option<T>, Error>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.values
+            .get(arg.name)
+            .map(|value| {
+                value.parse().map_err(|e| Error::Usage {
+                    message: format!("invalid value '{value}' for '{}': {e}", arg.label()),
+                    usage: self.usage.clone(),
+                })
+            })
+            .transpose()
+    }
+
+    /// Values collected by the [`ArgKind::Rest`] argument.
+    #[must_use]
+    pub fn rest(&self) -> &[String] {
+        &self.rest
+    }
+
+    /// Value of an option that selects a puzzle part, either 1 or 2.
+    pub fn part(&self, arg: &Arg) -> Result<Option<u8>, Error> {
+        match self.value(arg)? {
+            Some(part) if part != 1 && part != 2 => Err(Error::Usage {
+                message: format!(
+                    "invalid value '{part}' for '{}': expecting 1 or 2",
+                    arg.label()
+                ),
+                usage: self.usage.clone(),
+            }),
+            part => Ok(part),
+        }
+    }
+
+    /// Value of a positional argument, which is always present after parsing.
+    pub fn required<T>(&self, arg: &Arg) -> Result<T, Error>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.value(arg)?.ok_or_else(|| Error::Usage {
+            message: format!("missing argument '{}'", arg.label()),
+            usage: self.usage.clone(),
+        })
+    }
+}
+
+impl Command {
+    #[must_use]
+    pub fn usage(&self, prefix: &str) -> String {
+        [prefix.to_string(), self.name.to_string()]
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .chain(self.args.iter().map(|arg| arg.usage()))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    #[must_use]
+    pub fn help(&self, prefix: &str) -> String {
+        let mut lines = vec![
+            self.about.to_string(),
+            String::new(),
+            format!("{ANSI_BOLD}Usage:{ANSI_RESET} {}", self.usage(prefix)),
+            String::new(),
+            format!("{ANSI_BOLD}Arguments:{ANSI_RESET}"),
+        ];
+        lines.extend(format_table(
+            self.args
+                .iter()
+                .map(|arg| (arg.label(), arg.help))
+                .chain([("-h, --help".to_string(), "Print help.")]),
+        ));
+        lines.join("\n")
+    }
+
+    /// Parse the arguments following the command name.
+    pub fn parse<I: IntoIterator<Item = String>>(&self, args: I) -> Result<Matches, Error> {
+        self.parse_with_prefix(BIN_NAME, args)
+    }
+
+    fn parse_with_prefix<I: IntoIterator<Item = String>>(
+        &self,
+        prefix: &str,
+        args: I,
+    ) -> Result<Matches, Error> {
+        let usage = self.usage(prefix);
+        let usage_error = |message: String| Error::Usage {
+            message,
+            usage: usage.clone(),
+        };
+
+        let mut values = HashMap::new();
+        let mut rest = vec![];
+        let accepts_rest = self.args.iter().any(|arg| arg.kind == ArgKind::Rest);
+        let mut positionals = self
+            .args
+            .iter()
+            .filter(|arg| arg.kind == ArgKind::Positional);
+        let mut args = args.into_iter();
+
+        while let Some(raw) = args.next() {
+            if raw == "-h" || raw == "--help" {
+                return Err(Error::Help(self.help(prefix)));
+            }
+
+            let Some(name) = raw.strip_prefix("--") else {
+                match positionals.next() {
+                    Some(arg) => {
+                        values.insert(arg.name, raw);
+                    }
+                    None if accepts_rest => rest.push(raw),
+                    None => return Err(usage_error(format!("unexpected argument '{raw}'"))),
+                }
+                continue;
+            };
+
+            // accept both `--name value` and `--name=value`.
+            let (name, inline_value) = match name.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_string())),
+                None => (name, None),
+            };
+
+            let Some(arg) = self
+                .args
+                .iter()
+                .find(|arg| arg.is_named() && arg.name == name)
+            else {
+                return Err(usage_error(format!("unexpected argument '--{name}'")));
+            };
+
+            let value = match (arg.kind, inline_value) {
+                (ArgKind::Flag, None) => String::new(),
+                (ArgKind::Flag, Some(_)) => {
+                    return Err(usage_error(format!("'--{name}' does not take a value")));
+                }
+                (_, Some(value)) => value,
+                (_, None) => args.next().ok_or_else(|| {
+                    usage_error(format!("a value is required for '{}'", arg.label()))
+                })?,
+            };
+
+            if values.insert(arg.name, value).is_some() {
+                return Err(usage_error(format!(
+                    "'--{name}' cannot be used multiple times"
+                )));
+            }
+        }
+
+        if let Some(arg) = positionals.next() {
+            return Err(usage_error(format!("missing argument '{}'", arg.label())));
+        }
+
+        Ok(Matches {
+            values,
+            rest,
+            usage,
+        })
+    }
+}
+
+fn format_table<'a>(rows: impl Iterator<Item = (String, &'a str)>) -> Vec<String> {
+    let rows: Vec<_> = rows.collect();
+    let width = rows.iter().map(|(label, _)| label.len()).max().unwrap_or(0);
+    rows.iter()
+        .map(|(label, help)| format!("  {label:width$}  {help}"))
+        .collect()
+}
+
+#[must_use]
+pub fn find_command(name: &str) -> Option<&'static Command> {
+    COMMANDS.iter().find(|command| command.name == name)
+}
+
+#[must_use]
+pub fn help() -> String {
+    let mut lines = vec![
+        format!("{ANSI_BOLD}Usage:{ANSI_RESET} {BIN_NAME} <command> [arguments]"),
+        String::new(),
+        format!("{ANSI_BOLD}Commands:{ANSI_RESET}"),
+    ];
+    lines.extend(format_table(
+        COMMANDS
+            .iter()
+            .map(|command| (command.name.to_string(), command.about)),
+    ));
+    lines.push(String::new());
+    lines.push(format!(
+        "See '{BIN_NAME} <command> --help' for the arguments of a command."
+    ));
+    lines.join("\n")
+}
+
+/// Parse the command line of the template binary, without the program name.
+pub fn parse_command<I: IntoIterator<Item = String>>(
+    args: I,
+) -> Result<(&'static Command, Matches), Error> {
+    let mut args = args.into_iter();
+    let usage = format!("{BIN_NAME} <command> [arguments]");
+
+    match args.next().as_deref() {
+        None => Err(Error::Usage {
+            message: "no command specified".into(),
+            usage,
+        }),
+        Some("-h" | "--help" | "help") => Err(Error::Help(help())),
+        Some(name) => {
+            let command = find_command(name).ok_or_else(|| Error::Usage {
+                message: format!("unknown command '{name}'"),
+                usage,
+            })?;
+            Ok((command, command.parse(args)?))
+        }
+    }
+}
+
+/// Arguments of a solution binary, shared between `solve` and the binaries it runs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SolutionArgs {
+    pub time: bool,
+    pub submit: Option<u8>,
+    pub variants: bool,
+    pub samples: bool,
+}
+
+impl SolutionArgs {
+    pub fn from_matches(matches: &Matches) -> Result<Self, Error> {
+        Ok(Self {
+            time: matches.flag(&TIME),
+            submit: matches.part(&SUBMIT)?,
+            variants: matches.flag(&VARIANTS),
+            samples: matches.flag(&SAMPLES),
+        })
+    }
+
+    /// Arguments to pass on to a solution binary.
+    #[must_use]
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![];
+
+        if let Some(part) = self.submit {
+            args.push(format!("--{}", SUBMIT.name));
+            args.push(part.to_string());
+        }
+
+        if self.time {
+            args.push(format!("--{}", TIME.name));
+        }
+
+        if self.variants {
+            args.push(format!("--{}", VARIANTS.name));
+        }
+
+        if self.samples {
+            args.push(format!("--{}", SAMPLES.name));
+        }
+
+        args
+    }
+
+    /// Arguments of the running solution binary, parsed once. Exits on invalid arguments.
+    pub fn get() -> &'static Self {
+        static ARGS: OnceLock<SolutionArgs> = OnceLock::new();
+
+        ARGS.get_or_init(|| {
+            let command = Command {
+                name: "",
+                about: "Run the solution against the real input.",
+                args: SOLUTION_ARGS,
+            };
+            let program = std::env::args().next().unwrap_or_default();
+            command
+                .parse_with_prefix(&program, std::env::args().skip(1))
+                .and_then(|matches| Self::from_matches(&matches))
+                .unwrap_or_else(|e| e.exit())
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+impl FromStr for Shell {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bash" => Ok(Shell::Bash),
+            "zsh" => Ok(Shell::Zsh),
+            "fish" => Ok(Shell::Fish),
+            _ => Err("expecting one of bash, zsh, fish".into()),
+        }
+    }
+}
+
+/// Completion script for the template binary and the cargo aliases that run it.
+#[must_use]
+pub fn completions(shell: Shell) -> String {
+    match shell {
+        Shell::Bash => bash_completions(),
+        Shell::Zsh => zsh_completions(),
+        Shell::Fish => fish_completions(),
+    }
+}
+
+fn bash_completions() -> String {
+    let function = format!("_{BIN_NAME}");
+    let names = COMMANDS
+        .iter()
+        .map(|c| c.name)
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut lines = vec![
+        format!("{function}() {{"),
+        "    local cur prev command".into(),
+        "    cur=\"${COMP_WORDS[COMP_CWORD]}\"".into(),
+        "    prev=\"${COMP_WORDS[COMP_CWORD-1]}\"".into(),
+        "    command=\"${COMP_WORDS[1]}\"".into(),
+        "    if [[ $COMP_CWORD -eq 1 ]]; then".into(),
+        format!("        COMPREPLY=($(compgen -W \"{names}\" -- \"$cur\"))"),
+        "        return".into(),
+        "    fi".into(),
+        "    case \"$command\" in".into(),
+    ];
+
+    for command in COMMANDS {
+        lines.push(format!("        {})", command.name));
+
+        let options: Vec<_> = command
+            .args
+            .iter()
+            .filter(|a| a.kind == ArgKind::Option)
+            .collect();
+        if !options.is_empty() {
+            lines.push("            case \"$prev\" in".into());
+            for arg in options {
+                lines.push(format!(
+                    "                --{}) COMPREPLY=($(compgen -W \"{}\" -- \"$cur\")); return ;;",
+                    arg.name,
+                    arg.values.join(" ")
+                ));
+            }
+            lines.push("            esac".into());
+        }
+
+        let mut words: Vec<String> = command
+            .args
+            .iter()
+            .filter(|a| a.is_named())
+            .map(|a| format!("--{}", a.name))
+            .collect();
+        words.push("--help".into());
+        if let Some(positional) = command.args.iter().find(|a| a.kind == ArgKind::Positional) {
+            words.extend(positional.values.iter().map(ToString::to_string));
+        }
+        lines.push(format!(
+            "            COMPREPLY=($(compgen -W \"{}\" -- \"$cur\"))",
+            words.join(" ")
+        ));
+        lines.push("            ;;".into());
+    }
+
+    lines.push("    esac".into());
+    lines.push("}".into());
+    lines.push(format!("complete -F {function} {BIN_NAME}"));
+
+    // `cargo solve 1` has the same words as `advent_of_code solve 1`, so the cargo aliases
+    // reuse the function above and leave every other cargo command to cargo's own completions.
+    // bash-completion loads `_cargo` lazily, so it is loaded on first use; loading it registers
+    // `_cargo` for cargo, which the function takes back before handing over.
+    lines.extend([
+        String::new(),
+        format!("{function}_cargo() {{"),
+        format!(
+            "    if [[ $COMP_CWORD -gt 1 && \" {names} \" == *\" ${{COMP_WORDS[1]}} \"* ]]; then"
+        ),
+        format!("        {function}"),
+        "        return".into(),
+        "    fi".into(),
+        "    if ! declare -F _cargo >/dev/null; then".into(),
+        "        if declare -F _comp_load >/dev/null; then".into(),
+        "            _comp_load cargo".into(),
+        "        elif declare -F _completion_loader >/dev/null; then".into(),
+        "            _completion_loader cargo".into(),
+        "        fi".into(),
+        format!("        complete -F {function}_cargo cargo"),
+        "    fi".into(),
+        "    if declare -F _cargo >/dev/null; then".into(),
+        "        _cargo \"$@\"".into(),
+        "    fi".into(),
+        "}".into(),
+        format!("complete -F {function}_cargo cargo"),
+    ]);
+    lines.join("\n")
+}
+
+fn zsh_completions() -> String {
+    let function = format!("_{BIN_NAME}");
+    let names = COMMANDS
+        .iter()
+        .map(|c| c.name)
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mut lines = vec![
+        format!("#compdef {BIN_NAME}"),
+        String::new(),
+        format!("{function}() {{"),
+        "    local -a commands".into(),
+        "    commands=(".into(),
+    ];
+
+    for command in COMMANDS {
+        lines.push(format!(
+            "        '{}:{}'",
+            command.name,
+            zsh_escape(command.about)
+        ));
+    }
+
+    lines.extend([
+        "    )".into(),
+        "    if (( CURRENT == 2 )); then".into(),
+        "        _describe 'command' commands".into(),
+        "        return".into(),
+        "    fi".into(),
+        "    case $words[2] in".into(),
+    ]);
+
+    for command in COMMANDS {
+        let specs: Vec<String> = command
+            .args
+            .iter()
+            .map(|arg| {
+                let values = if arg.values.is_empty() {
+                    String::new()
+                } else {
+                    format!("({})", arg.values.join(" "))
+                };
+                match arg.kind {
+                    ArgKind::Flag => format!("'--{}[{}]'", arg.name, zsh_escape(arg.help)),
+                    ArgKind::Option => format!(
+                        "'--{}=[{}]:{}:{values}'",
+                        arg.name,
+                        zsh_escape(arg.help),
+                        arg.value_name
+                    ),
+                    ArgKind::Positional => format!("':{}:{values}'", arg.name),
+                    ArgKind::Rest => format!("'*:{}:'", arg.name),
+                }
+            })
+            .collect();
+
+        lines.push(format!(
+            "        {}) _arguments -s {} ;;",
+            command.name,
+            specs.join(" ")
+        ));
+    }
+
+    // see `bash_completions` for how the cargo aliases are completed; `_cargo` is autoloaded
+    // from `fpath` when compinit has not loaded it yet.
+    lines.extend([
+        "    esac".into(),
+        "}".into(),
+        String::new(),
+        format!("{function}_cargo() {{"),
+        format!("    if (( CURRENT > 2 && ${{{BIN_NAME}_commands[(Ie)$words[2]]}} )); then"),
+        format!("        {function}"),
+        "    else".into(),
+        "        (( $+functions[_cargo] )) || autoload -Uz +X _cargo 2>/dev/null".into(),
+        "        (( $+functions[_cargo] )) && _cargo \"$@\"".into(),
+        "    fi".into(),
+        "}".into(),
+        String::new(),
+        format!("{BIN_NAME}_commands=({names})"),
+        format!("compdef {function} {BIN_NAME}"),
+        format!("compdef {function}_cargo cargo"),
+    ]);
+    lines.join("\n")
+}
+
+fn zsh_escape(s: &str) -> String {
+    s.replace('\'', "'\\''")
+        .replace('[', "\\[")
+        .replace(']', "\\]")
+        .replace(':', "\\:")
+}
+
+fn fish_completions() -> String {
+    let names = COMMANDS
+        .iter()
+        .map(|c| c.name)
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mut lines = vec![format!("complete -c {BIN_NAME} -f")];
+
+    // fish merges completions of a program, so the cargo aliases are added next to cargo's own.
+    let programs = [
+        (BIN_NAME, format!("not __fish_seen_subcommand_from {names}")),
+        ("cargo", "__fish_use_subcommand".to_string()),
+    ];
+
+    for (program, top_level) in &programs {
+        for command in COMMANDS {
+            lines.push(format!(
+                "complete -c {program} -n '{top_level}' -a {} -d '{}'",
+                command.name,
+                fish_escape(command.about)
+            ));
+        }
+
+        for command in COMMANDS {
+            let condition = format!("__fish_seen_subcommand_from {}", command.name);
+            for arg in command.args {
+                let values = arg.values.join(" ");
+                let line = match arg.kind {
+                    ArgKind::Flag => format!(
+                        "complete -c {program} -n '{condition}' -l {} -d '{}'",
+                        arg.name,
+                        fish_escape(arg.help)
+                    ),
+                    ArgKind::Option if values.is_empty() => format!(
+                        "complete -c {program} -n '{condition}' -l {} -r -d '{}'",
+                        arg.name,
+                        fish_escape(arg.help)
+                    ),
+                    ArgKind::Option => format!(
+                        "complete -c {program} -n '{condition}' -l {} -r -a '{values}' -d '{}'",
+                        arg.name,
+                        fish_escape(arg.help)
+                    ),
+                    ArgKind::Positional => {
+                        format!("complete -c {program} -n '{condition}' -a '{values}'")
+                    }
+                    ArgKind::Rest => continue,
+                };
+                lines.push(line);
+            }
+        }
+    }
+
+    lines.join("\n")
+}
+
+fn fish_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('\'', "\\'")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        completions, find_command, parse_command, Error, Shell, SolutionArgs, COLUMNS, DAY,
+        RELEASE, SUBMIT, TAG,
+    };
+    use crate::Day;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn parses_commands() {
+        let (command, matches) = parse_command(args("solve 5 --release --submit 2")).unwrap();
+        assert_eq!(command.name, "solve");
+        assert_eq!(matches.required::<Day>(&DAY).unwrap().into_inner(), 5);
+        assert!(matches.flag(&RELEASE));
+        assert_eq!(
+            SolutionArgs::from_matches(&matches).unwrap(),
+            SolutionArgs {
+                time: false,
+                submit: Some(2),
+                variants: false,
+                samples: false,
+            }
+        );
+
+        let (_, matches) = parse_command(args("all --columns=part1,total")).unwrap();
+        assert_eq!(
+            matches.value::<String>(&COLUMNS).unwrap().as_deref(),
+            Some("part1,total")
+        );
+
+        let (_, matches) = parse_command(args("search hail --tag grid stones")).unwrap();
+        assert_eq!(matches.rest(), ["hail", "stones"]);
+        assert_eq!(
+            matches.value::<String>(&TAG).unwrap().as_deref(),
+            Some("grid")
+        );
+    }
+
+    #[test]
+    fn reports_usage_errors() {
+        let usage_message = |s: &str| match parse_command(args(s)) {
+            Err(Error::Usage { message, .. }) => message,
+            other => panic!("expected usage error, got {other:?}"),
+        };
+
+        assert_eq!(usage_message("frobnicate"), "unknown command 'frobnicate'");
+        assert_eq!(usage_message("solve"), "missing argument '<day>'");
+        assert_eq!(
+            usage_message("solve 1 --fast"),
+            "unexpected argument '--fast'"
+        );
+        assert_eq!(
+            usage_message("solve 1 --submit"),
+            "a value is required for '--submit <part>'"
+        );
+
+        let (_, matches) = parse_command(args("solve 1 --submit 3")).unwrap();
+        assert!(SolutionArgs::from_matches(&matches).is_err());
+        let (_, matches) = parse_command(args("scaffold 26")).unwrap();
+        assert!(matches.required::<Day>(&DAY).is_err());
+    }
+
+    #[test]
+    fn prints_help() {
+        let Err(Error::Help(help)) = parse_command(args("solve --help")) else {
+            panic!("expected help");
+        };
+        assert!(help.contains("advent_of_code solve <day> [--release]"));
+        assert!(help.contains(SUBMIT.help));
+
+        let Err(Error::Help(help)) = parse_command(args("--help")) else {
+            panic!("expected help");
+        };
+        assert!(help.contains(find_command("watch-day").unwrap().about));
+    }
+
+    #[test]
+    fn roundtrips_solution_args() {
+        let solution_args = SolutionArgs {
+            time: true,
+            submit: Some(1),
+            variants: true,
+            samples: true,
+        };
+        let (_, matches) = parse_command(
+            ["solve".to_string(), "1".to_string()]
+                .into_iter()
+                .chain(solution_args.to_args()),
+        )
+        .unwrap();
+        assert_eq!(SolutionArgs::from_matches(&matches).unwrap(), solution_args);
+    }
+
+    #[test]
+    fn generates_completions() {
+        assert!(completions(Shell::Bash).contains("complete -F _advent_of_code advent_of_code"));
+        assert!(completions(Shell::Zsh).contains("solve) _arguments"));
+        assert!(completions(Shell::Fish)
+            .contains("-n '__fish_seen_subcommand_from all' -l sort -r -a 'day slowest fastest'"));
+    }
+
+    #[test]
+    fn generates_completions_for_cargo_aliases() {
+        let bash = completions(Shell::Bash);
+        assert!(bash.contains("complete -F _advent_of_code_cargo cargo"));
+        assert!(bash.contains("\" await scaffold download read inputs solve all time "));
+        assert!(completions(Shell::Zsh).contains("compdef _advent_of_code_cargo cargo"));
+        assert!(completions(Shell::Fish).contains(
+            "complete -c cargo -n '__fish_use_subcommand' -a solve -d 'Run the solution"
+        ));
+        assert!(completions(Shell::Fish)
+            .contains("complete -c cargo -n '__fish_seen_subcommand_from solve' -l time"));
+    }
+
+    #[test]
+    fn loads_cargo_completions_before_taking_over_cargo() {
+        let bash = completions(Shell::Bash);
+        let wrapper = &bash[bash.find("_advent_of_code_cargo() {").unwrap()..];
+        let handover = wrapper.find("_cargo \"$@\"").unwrap();
+        for loader in ["_comp_load cargo", "_completion_loader cargo"] {
+            assert!(
+                wrapper.find(loader).is_some_and(|i| i < handover),
+                "{loader}"
+            );
+        }
+        // cargo is registered once up front and re-registered right after its loader ran.
+        let registrations = bash
+            .lines()
+            .filter(|l| l.trim_start().starts_with("complete ") && l.ends_with(" cargo"))
+            .count();
+        assert_eq!(registrations, 2);
+        assert!(
+            wrapper
+                .find("        complete -F _advent_of_code_cargo cargo")
+                .unwrap()
+                < handover
+        );
+
+        let zsh = completions(Shell::Zsh);
+        let autoload = zsh.find("autoload -Uz +X _cargo").unwrap();
+        assert!(autoload < zsh.find("_cargo \"$@\"").unwrap());
+        assert!(autoload < zsh.find("compdef _advent_of_code_cargo cargo").unwrap());
+    }
+}
@@ -1,39 +1,103 @@
-use std::io;
+use std::{io, process, time::Duration};
 
 use crate::template::{
-    readme_benchmarks::{self, Timings},
+    cli::SolutionArgs,
+    notes::Notes,
+    readme_benchmarks::{self, TableOptions, Timings},
+    readme_chart,
+    tags::Tags,
     ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
 use crate::{all_days, Day};
 
-pub fn handle(is_release: bool, is_timed: bool) {
+/// Run all days, or only the days that have every tag in `tag_filter`.
+pub fn handle(
+    is_release: bool,
+    is_timed: bool,
+    table_options: &TableOptions,
+    tag_filter: &[String],
+) {
+    let tags = match Tags::load() {
+        Ok(tags) => tags,
+        Err(e) => {
+            eprintln!("Failed to read tags: {e}");
+            process::exit(1);
+        }
+    };
+
+    // notes are optional, the column stays empty without them.
+    let notes = Notes::load().unwrap_or_else(|e| {
+        eprintln!("Failed to read notes: {e}");
+        Notes::default()
+    });
+
+    let days: Vec<Day> = all_days()
+        .filter(|&day| tags.matches(day, tag_filter))
+        .collect();
+
+    if days.is_empty() {
+        eprintln!("No days tagged \"{}\".", tag_filter.join(","));
+        process::exit(1);
+    }
+
     let mut timings: Vec<Timings> = vec![];
 
-    all_days().for_each(|day| {
-        if day > 1 {
+    days.iter().enumerate().for_each(|(i, &day)| {
+        if i > 0 {
             println!();
         }
 
         println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
         println!("------");
 
-        let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+        let solution_args = SolutionArgs {
+            time: is_timed,
+            ..SolutionArgs::default()
+        };
+        let output = child_commands::run_solution(day, &solution_args, is_release).unwrap();
 
         if output.is_empty() {
             println!("Not solved.");
         } else {
-            let val = child_commands::parse_exec_time(&output, day);
+            let mut val = child_commands::parse_exec_time(&output, day);
+            val.note = notes.get(day).map(ToString::to_string);
             timings.push(val);
         }
     });
 
     if is_timed {
-        let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
+        let total_millis = timings
+            .iter()
+            .map(Timings::total)
+            .sum::<Duration>()
+            .as_secs_f64()
+            * 1000_f64;
 
         println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
 
-        if is_release {
-            match readme_benchmarks::update(timings, total_millis) {
+        if is_release && !tag_filter.is_empty() {
+            println!(
+                "Not updating the README, benchmarks only cover the days tagged \"{}\".",
+                tag_filter.join(",")
+            );
+        } else if is_release {
+            let mut table_options = table_options.clone();
+
+            match readme_chart::update(&timings) {
+                Ok(true) => {
+                    println!(
+                        "Successfully wrote benchmark chart to \"{}\".",
+                        readme_chart::CHART_PATH
+                    );
+                    table_options.chart = Some(readme_chart::CHART_PATH.into());
+                }
+                Ok(false) => {}
+                Err(_) => {
+                    eprintln!("Failed to write benchmark chart.");
+                }
+            }
+
+            match readme_benchmarks::update(timings, &table_options) {
                 Ok(()) => println!("Successfully updated README with benchmarks."),
                 Err(_) => {
                     eprintln!("Failed to update readme with benchmarks.");
@@ -56,27 +120,32 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 /// All solutions live in isolated binaries.
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-mod child_commands {
-    use super::{get_path_for_bin, Error};
+pub(crate) mod child_commands {
+    use super::Error;
+    use crate::template::{
+        cli::SolutionArgs,
+        config,
+        readme_benchmarks::PartTiming,
+        runner::{PARSE_LABEL, SAMPLES_LABEL},
+    };
     use crate::Day;
     use std::{
         io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        process::{Child, Command, Stdio},
         thread,
+        time::{Duration, Instant},
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    /// Run the solution bin for a given day, passing on `solution_args`.
+    pub fn run_solution(
+        day: Day,
+        solution_args: &SolutionArgs,
+        is_release: bool,
+    ) -> Result<Vec<String>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
+        if !config::get().bin_path(day).exists() {
             return Ok(vec![]);
         }
 
@@ -87,10 +156,10 @@ mod child_commands {
             args.push("--release");
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
+        let solution_args = solution_args.to_args();
+        if !solution_args.is_empty() {
             args.push("--");
-            args.push("--time");
+            args.extend(solution_args.iter().map(String::as_str));
         }
 
         // spawn child command with piped stdout/stderr.
@@ -105,85 +174,126 @@ mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
-
         let thread = thread::spawn(move || {
             stderr.lines().for_each(|line| {
                 eprintln!("{}", line.unwrap());
             });
         });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
-        }
+        let reader = thread::spawn(move || {
+            let mut output = vec![];
+            for line in stdout.lines() {
+                let line = line.unwrap();
+                // raw samples are only meant for parsing.
+                if !line.contains(SAMPLES_LABEL) {
+                    println!("{line}");
+                }
+                output.push(line);
+            }
+            output
+        });
+
+        wait_with_timeout(&mut cmd, config::get().timeouts.solution)?;
 
         thread.join().unwrap();
-        cmd.wait()?;
+        Ok(reader.join().unwrap())
+    }
 
-        Ok(output)
+    /// Wait for a child to exit, killing it once the timeout elapsed.
+    fn wait_with_timeout(cmd: &mut Child, timeout: Option<Duration>) -> Result<(), Error> {
+        let Some(timeout) = timeout else {
+            cmd.wait()?;
+            return Ok(());
+        };
+
+        let start = Instant::now();
+        while cmd.try_wait()?.is_none() {
+            if start.elapsed() >= timeout {
+                cmd.kill()?;
+                cmd.wait()?;
+                eprintln!("Stopped after the timeout of {timeout:?}.");
+                break;
+            }
+            thread::sleep(Duration::from_millis(50));
+        }
+
+        Ok(())
     }
 
     pub fn parse_exec_time(output: &[String], day: Day) -> super::Timings {
-        let mut timings = super::Timings {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+        let mut timings = super::Timings::new(day);
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+        for l in output {
+            if let Some(peak_memory) = parse_peak_memory(l) {
+                timings.peak_memory = Some(peak_memory);
+                continue;
+            }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+            if !l.contains(" samples)") {
+                continue;
+            }
 
-                timings.total_nanos += nanos;
-            });
+            let Some((nanos, samples)) = parse_time(l) else {
+                eprintln!("Could not parse timings from line: {l}");
+                continue;
+            };
+
+            let Some(part) = l.split(':').next() else {
+                continue;
+            };
+
+            let timing = PartTiming {
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                duration: Duration::from_nanos(nanos.round() as u64),
+                samples,
+            };
+
+            if part.contains("Part 1") {
+                timings.part_1 = Some(timing);
+            } else if part.contains("Part 2") {
+                timings.part_2 = Some(timing);
+            } else if part.ends_with(PARSE_LABEL) {
+                timings.parse = Some(timing.duration);
+            }
+        }
 
         timings
     }
 
+    /// Parse the peak memory usage reported by the runner, e.g. `Peak memory: 2048 kB`, to bytes.
+    fn parse_peak_memory(line: &str) -> Option<u64> {
+        let kilobytes = line
+            .strip_prefix("Peak memory: ")?
+            .strip_suffix(" kB")?
+            .trim()
+            .parse::<u64>()
+            .ok()?;
+        Some(kilobytes * 1024)
+    }
+
     fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
         s.split(postfix).next()?.parse().ok()
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
+    fn parse_time(line: &str) -> Option<(f64, u128)> {
         // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
+        let (str_timing, str_samples) = line
             .split(" samples)")
             .next()?
             .split('(')
             .last()?
-            .split('@')
-            .next()?
-            .trim();
+            .split_once('@')?;
 
-        let parsed_timing = match str_timing {
+        let parsed_timing = match str_timing.trim() {
             s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
             s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
             s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
             s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
         }?;
 
-        Some((str_timing, parsed_timing))
+        let samples = str_samples.trim().parse().ok()?;
+
+        Some((parsed_timing, samples))
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -205,6 +315,7 @@ mod child_commands {
         use super::parse_exec_time;
 
         use crate::day;
+        use std::time::Duration;
 
         #[test]
         fn test_well_formed() {
@@ -216,9 +327,24 @@ mod child_commands {
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.total().as_secs_f64() * 1e9, 74130074_f64);
+            assert_eq!(res.part_1.unwrap().duration, Duration::from_nanos(74));
+            assert_eq!(res.part_1.unwrap().samples, 100000);
+            assert_eq!(res.part_2.unwrap().duration, Duration::from_micros(74130));
+            assert_eq!(res.part_2.unwrap().samples, 99999);
+        }
+
+        #[test]
+        fn test_parse_time() {
+            let res = parse_exec_time(
+                &[
+                    " > benching\rParse: (1.5µs @ 100 samples)".into(),
+                    "Part 1: 0 (2.0µs @ 100 samples)".into(),
+                ],
+                day!(1),
+            );
+            assert_eq!(res.parse, Some(Duration::from_nanos(1500)));
+            assert_eq!(res.total(), Duration::from_nanos(2000));
         }
 
         #[test]
@@ -231,9 +357,9 @@ mod child_commands {
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_approx_eq!(res.total().as_secs_f64() * 1e9, 2100000000_f64);
+            assert_eq!(res.part_1.unwrap().duration, Duration::from_secs(2));
+            assert_eq!(res.part_2.unwrap().duration, Duration::from_millis(100));
         }
 
         #[test]
@@ -246,9 +372,21 @@ mod child_commands {
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 0_f64);
+            assert_approx_eq!(res.total().as_secs_f64(), 0_f64);
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
         }
+
+        #[test]
+        fn test_peak_memory() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1: 0 (1.0ms @ 10 samples)".into(),
+                    "Peak memory: 2048 kB".into(),
+                ],
+                day!(1),
+            );
+            assert_eq!(res.peak_memory, Some(2048 * 1024));
+        }
     }
 }
@@ -0,0 +1,76 @@
+use std::process;
+
+use crate::template::{
+    bench_report::{self, Run},
+    cli::SolutionArgs,
+    commands::all::child_commands,
+    tags::Tags,
+    ANSI_BOLD, ANSI_RESET,
+};
+use crate::{all_days, Day};
+
+/// Bench all days, or only the days that have every tag in `tag_filter`, and write the HTML report.
+pub fn handle(tag_filter: &[String]) {
+    let tags = match Tags::load() {
+        Ok(tags) => tags,
+        Err(e) => {
+            eprintln!("Failed to read tags: {e}");
+            process::exit(1);
+        }
+    };
+
+    let days: Vec<Day> = all_days()
+        .filter(|&day| tags.matches(day, tag_filter))
+        .collect();
+
+    if days.is_empty() {
+        eprintln!("No days tagged \"{}\".", tag_filter.join(","));
+        process::exit(1);
+    }
+
+    let previous = match Run::load_last() {
+        Ok(previous) => previous,
+        Err(e) => {
+            eprintln!("Failed to read the previous run: {e}");
+            process::exit(1);
+        }
+    };
+
+    let solution_args = SolutionArgs {
+        time: true,
+        samples: true,
+        ..SolutionArgs::default()
+    };
+    let mut run = Run::default();
+
+    for (i, &day) in days.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+
+        match child_commands::run_solution(day, &solution_args, true) {
+            Ok(output) if output.is_empty() => println!("Not solved."),
+            Ok(output) => run.add_output(day, &output),
+            Err(e) => eprintln!("Failed to run day {day}: {e:?}"),
+        }
+    }
+
+    if run.series.is_empty() {
+        eprintln!("No samples were recorded, nothing to report.");
+        process::exit(1);
+    }
+
+    match bench_report::write(&run, previous.as_ref()) {
+        Ok(path) => println!(
+            "\nSuccessfully wrote benchmark report to \"{}\".",
+            path.display()
+        ),
+        Err(e) => {
+            eprintln!("Failed to write benchmark report: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,422 @@
+/// Implementation of `await`: counts down to the next puzzle unlock, then downloads and scaffolds the day.
+/// Puzzles unlock at midnight EST (UTC-5) on December 1st to 25th.
+use std::{
+    io::{stdout, Write},
+    process, thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::template::{
+    aoc_cli::{self, AocCommandError},
+    commands::scaffold,
+    config::{self, Config},
+};
+use crate::{all_days, Day};
+
+const SECS_PER_DAY: u64 = 24 * 60 * 60;
+/// Offset of midnight EST from midnight UTC.
+const UNLOCK_OFFSET_SECS: u64 = 5 * 60 * 60;
+const MAX_ATTEMPTS: u32 = 8;
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Source of the current time, replaced in tests to avoid waiting.
+pub trait Clock {
+    /// Time since the unix epoch.
+    fn now(&self) -> Duration;
+    fn sleep(&self, duration: Duration);
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+/// Where puzzles are downloaded from, replaced in tests to control when the server is ready.
+pub trait Server {
+    fn download(&self, day: Day) -> Result<(), AocCommandError>;
+}
+
+/// Downloads through the aoc-cli command of a config, same as the `download` command.
+pub struct AocCli<'a>(pub &'a Config);
+
+impl Server for AocCli<'_> {
+    fn download(&self, day: Day) -> Result<(), AocCommandError> {
+        aoc_cli::download_with(self.0, day).map(|_| ())
+    }
+}
+
+/// Days since the unix epoch of a date in the proleptic gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+fn start_of_year(year: u16) -> u64 {
+    u64::try_from(days_from_civil(year.into(), 1, 1)).unwrap_or_default() * SECS_PER_DAY
+}
+
+/// The UTC year of a timestamp.
+fn year_of(now: Duration) -> u16 {
+    let secs = now.as_secs();
+    #[allow(clippy::cast_possible_truncation)]
+    let mut year = (1970 + secs / 31_556_952) as u16;
+
+    if start_of_year(year) > secs {
+        year -= 1;
+    } else if start_of_year(year + 1) <= secs {
+        year += 1;
+    }
+
+    year
+}
+
+/// Time since the unix epoch at which the puzzle of a day unlocks.
+#[must_use]
+pub fn unlock_time(year: u16, day: Day) -> Duration {
+    let days = days_from_civil(year.into(), 12, day.into_inner().into());
+    Duration::from_secs(u64::try_from(days).unwrap_or_default() * SECS_PER_DAY + UNLOCK_OFFSET_SECS)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Target {
+    /// The day is unlocked but was not downloaded yet.
+    Unlocked(Day),
+    /// The next day to unlock, at the given time.
+    Locked(Day, Duration),
+    /// All puzzles of the event are unlocked and downloaded.
+    Over,
+}
+
+/// The most recently unlocked day if it has no input yet, otherwise the next day to unlock.
+pub fn next_target(year: u16, now: Duration, is_downloaded: impl Fn(Day) -> bool) -> Target {
+    let latest = all_days()
+        .take_while(|&day| unlock_time(year, day) <= now)
+        .last();
+
+    if let Some(day) = latest.filter(|&day| !is_downloaded(day)) {
+        return Target::Unlocked(day);
+    }
+
+    all_days()
+        .find(|&day| unlock_time(year, day) > now)
+        .map_or(Target::Over, |day| {
+            Target::Locked(day, unlock_time(year, day))
+        })
+}
+
+fn format_remaining(remaining: Duration) -> String {
+    let secs = remaining.as_secs();
+    let (days, secs) = (secs / SECS_PER_DAY, secs % SECS_PER_DAY);
+    let clock = format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60);
+
+    match days {
+        0 => clock,
+        1 => format!("1 day {clock}"),
+        days => format!("{days} days {clock}"),
+    }
+}
+
+/// Print a countdown until the unlock time is reached.
+pub fn countdown(day: Day, unlock: Duration, clock: &impl Clock) {
+    loop {
+        let remaining = unlock.saturating_sub(clock.now());
+        if remaining.is_zero() {
+            break;
+        }
+
+        // round up, so the countdown ends on zero instead of starting one second early.
+        let shown =
+            Duration::from_secs(remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0));
+        print!("\r🎄 Day {day} unlocks in {}  ", format_remaining(shown));
+        let _ = stdout().flush();
+
+        clock.sleep(remaining.min(Duration::from_secs(1)));
+    }
+
+    println!("\r🎄 Day {day} is unlocked!{}", " ".repeat(24));
+}
+
+/// Download a day, retrying with exponential backoff while the server is not ready yet.
+pub fn download_with_retry(
+    day: Day,
+    server: &impl Server,
+    clock: &impl Clock,
+) -> Result<(), AocCommandError> {
+    let mut backoff = Duration::from_secs(1);
+    let mut attempt = 1;
+
+    loop {
+        match server.download(day) {
+            Ok(()) => return Ok(()),
+            Err(e) if attempt >= MAX_ATTEMPTS => return Err(e),
+            Err(e) => {
+                attempt += 1;
+                eprintln!(
+                    "Download failed ({e}), retrying in {}s (attempt {attempt}/{MAX_ATTEMPTS}).",
+                    backoff.as_secs()
+                );
+                clock.sleep(backoff);
+                backoff = (backoff * 2).min(MAX_BACKOFF);
+            }
+        }
+    }
+}
+
+fn is_downloaded(day: Day) -> bool {
+    config::get()
+        .input_path(day)
+        .metadata()
+        .is_ok_and(|m| m.len() > 0)
+}
+
+pub fn handle() {
+    if aoc_cli::check().is_err() {
+        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+        process::exit(1);
+    }
+
+    let clock = SystemClock;
+    let year = config::get().year.unwrap_or_else(|| year_of(clock.now()));
+
+    let day = match next_target(year, clock.now(), is_downloaded) {
+        Target::Unlocked(day) => day,
+        Target::Locked(day, unlock) => {
+            countdown(day, unlock, &clock);
+            day
+        }
+        Target::Over => {
+            println!("🎄 All puzzles of {year} are unlocked and downloaded.");
+            return;
+        }
+    };
+
+    if let Err(e) = download_with_retry(day, &AocCli(config::get()), &clock) {
+        eprintln!("failed to download day {day}: {e}");
+        process::exit(1);
+    }
+
+    if !config::get().solution_path(day).exists() {
+        println!();
+        scaffold::handle(day);
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{cell::Cell, time::Duration};
+
+    use super::{
+        countdown, download_with_retry, format_remaining, next_target, unlock_time, year_of,
+        AocCli, Clock, Server, Target,
+    };
+    use crate::day;
+    use crate::template::{aoc_cli::AocCommandError, config::Config};
+    use crate::Day;
+
+    /// 2023-12-01 05:00:00 UTC.
+    const DAY_ONE_2023: Duration = Duration::from_secs(1_701_406_800);
+
+    struct MockClock {
+        now: Cell<Duration>,
+        slept: Cell<Duration>,
+    }
+
+    impl MockClock {
+        fn at(now: Duration) -> Self {
+            Self {
+                now: Cell::new(now),
+                slept: Cell::new(Duration::ZERO),
+            }
+        }
+    }
+
+    impl Clock for MockClock {
+        fn now(&self) -> Duration {
+            self.now.get()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            self.now.set(self.now.get() + duration);
+            self.slept.set(self.slept.get() + duration);
+        }
+    }
+
+    /// In-process mock of the download that fails until the puzzle is unlocked, plus a number of extra failures.
+    struct MockServer<'a> {
+        clock: &'a MockClock,
+        unlock: Duration,
+        failures: Cell<u32>,
+        requests: Cell<u32>,
+    }
+
+    impl Server for MockServer<'_> {
+        fn download(&self, _day: Day) -> Result<(), AocCommandError> {
+            self.requests.set(self.requests.get() + 1);
+
+            if self.clock.now() < self.unlock {
+                return Err(AocCommandError::CommandNotCallable);
+            }
+            if self.failures.get() > 0 {
+                self.failures.set(self.failures.get() - 1);
+                return Err(AocCommandError::CommandNotCallable);
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn computes_unlock_times() {
+        assert_eq!(unlock_time(2023, day!(1)), DAY_ONE_2023);
+        assert_eq!(
+            unlock_time(2024, day!(25)),
+            Duration::from_secs(1_735_102_800)
+        );
+        assert_eq!(year_of(DAY_ONE_2023), 2023);
+        assert_eq!(year_of(Duration::from_secs(1_704_067_200)), 2024);
+        assert_eq!(year_of(Duration::from_secs(1_704_067_199)), 2023);
+    }
+
+    #[test]
+    fn finds_next_target() {
+        let hour = Duration::from_secs(3600);
+
+        assert_eq!(
+            next_target(2023, DAY_ONE_2023 - hour, |_| false),
+            Target::Locked(day!(1), DAY_ONE_2023)
+        );
+        assert_eq!(
+            next_target(2023, DAY_ONE_2023 + hour, |_| false),
+            Target::Unlocked(day!(1))
+        );
+        assert_eq!(
+            next_target(2023, DAY_ONE_2023 + hour, |_| true),
+            Target::Locked(day!(2), unlock_time(2023, day!(2)))
+        );
+        assert_eq!(
+            next_target(2023, unlock_time(2023, day!(25)), |_| true),
+            Target::Over
+        );
+    }
+
+    #[test]
+    fn counts_down_to_unlock() {
+        let clock = MockClock::at(DAY_ONE_2023 - Duration::from_millis(2500));
+        countdown(day!(1), DAY_ONE_2023, &clock);
+
+        assert_eq!(clock.now(), DAY_ONE_2023);
+        assert_eq!(format_remaining(Duration::from_secs(3725)), "01:02:05");
+        assert_eq!(
+            format_remaining(Duration::from_secs(2 * 86_400 + 59)),
+            "2 days 00:00:59"
+        );
+    }
+
+    #[test]
+    fn retries_with_backoff() {
+        let clock = MockClock::at(DAY_ONE_2023);
+        let server = MockServer {
+            clock: &clock,
+            unlock: DAY_ONE_2023 + Duration::from_secs(5),
+            failures: Cell::new(0),
+            requests: Cell::new(0),
+        };
+
+        assert!(download_with_retry(day!(1), &server, &clock).is_ok());
+        // waits 1s, 2s and 4s before the server is ready.
+        assert_eq!(server.requests.get(), 4);
+        assert_eq!(clock.slept.get(), Duration::from_secs(7));
+
+        server.failures.set(100);
+        assert!(download_with_retry(day!(1), &server, &clock).is_err());
+        assert_eq!(server.requests.get(), 12);
+    }
+
+    /// Stand-in for aoc-cli that fetches the input from a local server and fails on any status but 200.
+    #[cfg(unix)]
+    const FAKE_AOC_CLI: &str = r#"
+while [ $# -gt 0 ]; do
+    case "$1" in
+        --input-file) input="$2"; shift ;;
+        --puzzle-file) puzzle="$2"; shift ;;
+    esac
+    shift
+done
+exec 3<>"/dev/tcp/127.0.0.1/$AOC_TEST_PORT"
+printf 'GET /2023/day/1/input HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n' >&3
+response=$(cat <&3)
+case "$response" in
+    "HTTP/1.1 200 "*) ;;
+    *) exit 1 ;;
+esac
+printf '%s\n' "${response#*$'\r\n\r\n'}" > "$input"
+echo "--- Day 1 ---" > "$puzzle"
+"#;
+
+    #[test]
+    #[cfg(unix)]
+    fn retries_aoc_cli_against_local_server() {
+        use std::{
+            fs,
+            io::{BufRead, BufReader, Write},
+            net::TcpListener,
+            process, thread,
+        };
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = thread::spawn(move || {
+            let responses = [
+                "HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\n\r\n",
+                "HTTP/1.1 200 OK\r\nContent-Length: 6\r\n\r\n1\n2\n3\n",
+            ];
+            for response in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                // read the whole request, closing with unread data resets the connection.
+                let request: Vec<_> = BufReader::new(&stream)
+                    .lines()
+                    .map(Result::unwrap)
+                    .take_while(|line| !line.is_empty())
+                    .collect();
+                assert_eq!(request[0], "GET /2023/day/1/input HTTP/1.1");
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        let dir = std::env::temp_dir().join(format!("aoc-countdown-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let script = dir.join("aoc.sh");
+        fs::write(&script, FAKE_AOC_CLI).unwrap();
+
+        let mut config = Config::default();
+        config.year = Some(2023);
+        config.aoc_cli = format!("env AOC_TEST_PORT={port} bash {}", script.display());
+        config.paths.inputs.clone_from(&dir);
+        config.paths.puzzles.clone_from(&dir);
+        config.paths.solve_log = dir.join("solve_log.txt");
+
+        let clock = MockClock::at(DAY_ONE_2023);
+        let result = download_with_retry(day!(1), &AocCli(&config), &clock);
+        server.join().unwrap();
+
+        assert!(result.is_ok());
+        assert_eq!(clock.slept.get(), Duration::from_secs(1));
+        assert_eq!(
+            fs::read_to_string(config.input_path(day!(1))).unwrap(),
+            "1\n2\n3\n"
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
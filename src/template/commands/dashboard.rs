@@ -0,0 +1,607 @@
+/// Interactive terminal dashboard showing the status of all days.
+/// Commands for the selected day run in the background, their output is collected in a scrolling pane.
+use std::{
+    fs,
+    io::{self, BufRead, BufReader, Read, Write},
+    process::{self, Command, Stdio},
+    sync::mpsc::{self, Receiver, Sender},
+    thread,
+    time::Duration,
+};
+
+use crossterm::{
+    cursor::{Hide, MoveTo, Show},
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute, queue,
+    style::Print,
+    terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+};
+
+use crate::template::{
+    aoc_cli,
+    bench_report::Run,
+    cli::SolutionArgs,
+    commands::{
+        all::child_commands::parse_exec_time,
+        watch::{passed_tests, test_args},
+    },
+    config, input, markdown,
+    progress::Progress,
+    readme_benchmarks::{PartTiming, Timings},
+    strip_ansi, Normalization,
+};
+use crate::{all_days, Day};
+
+const TICK: Duration = Duration::from_millis(100);
+const KEY_HELP: &str =
+    "t tests · r run · b bench · p puzzle · ↑↓ select · PgUp/PgDn scroll · q quit";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Job {
+    Tests,
+    Run,
+    Bench,
+}
+
+impl Job {
+    fn label(self) -> &'static str {
+        match self {
+            Job::Tests => "example tests",
+            Job::Run => "real input",
+            Job::Bench => "benchmark",
+        }
+    }
+
+    fn cargo_args(self, day: Day) -> Vec<String> {
+        let bin = day.to_string();
+        match self {
+            Job::Tests => test_args(day),
+            Job::Run => ["run", "--quiet", "--bin", &bin]
+                .map(str::to_string)
+                .to_vec(),
+            Job::Bench => {
+                let mut args = ["run", "--quiet", "--release", "--bin", &bin, "--"]
+                    .map(str::to_string)
+                    .to_vec();
+                args.extend(
+                    SolutionArgs {
+                        time: true,
+                        ..SolutionArgs::default()
+                    }
+                    .to_args(),
+                );
+                args
+            }
+        }
+    }
+}
+
+enum Message {
+    Line(String),
+    Done(bool),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum InputStatus {
+    Missing,
+    Invalid,
+    Ok,
+}
+
+/// Status of a day, as shown in a row of the calendar.
+#[derive(Clone, Debug)]
+struct DayStatus {
+    day: Day,
+    scaffolded: bool,
+    input: InputStatus,
+    /// Outcome of the last example test run in this session.
+    tests: Option<bool>,
+    stars: usize,
+    timings: Option<Timings>,
+}
+
+impl DayStatus {
+    fn load(day: Day, progress: &Progress) -> Self {
+        let input = match input::load(&config::get().input_path(day), Normalization::RAW) {
+            Ok(_) => InputStatus::Ok,
+            Err(input::InputIssue::Missing) => InputStatus::Missing,
+            Err(_) => InputStatus::Invalid,
+        };
+
+        Self {
+            day,
+            scaffolded: config::get().bin_path(day).exists(),
+            input,
+            tests: None,
+            stars: progress.stars(day),
+            timings: None,
+        }
+    }
+
+    /// Mean timings of the samples saved by the last `bench-report`, `None` if no part was benched.
+    fn last_timings(run: &Run, day: Day) -> Option<Timings> {
+        let timing = |part| {
+            let samples = &run.get(day, part)?.samples;
+            let count = u64::try_from(samples.len()).ok().filter(|&n| n > 0)?;
+            Some(PartTiming {
+                duration: Duration::from_nanos(samples.iter().sum::<u64>() / count),
+                samples: count.into(),
+            })
+        };
+
+        let (part_1, part_2) = (timing(1), timing(2));
+        if part_1.is_none() && part_2.is_none() {
+            return None;
+        }
+
+        Some(Timings {
+            part_1,
+            part_2,
+            ..Timings::new(day)
+        })
+    }
+
+    fn row(&self) -> String {
+        let check = |ok: bool| if ok { "✔" } else { "·" };
+        let input = match self.input {
+            InputStatus::Ok => "✔",
+            InputStatus::Missing => "·",
+            InputStatus::Invalid => "✖",
+        };
+        let tests = match self.tests {
+            Some(true) => "✔",
+            Some(false) => "✖",
+            None => "·",
+        };
+        let stars = match self.stars {
+            2 => "⭐⭐",
+            1 => "⭐ ·",
+            _ => "· · ",
+        };
+        let timing = |part: Option<PartTiming>| {
+            part.map_or_else(|| "-".to_string(), |p| format!("{:.1?}", p.duration))
+        };
+        let (part_1, part_2) = match &self.timings {
+            Some(t) => (timing(t.part_1), timing(t.part_2)),
+            None => ("-".into(), "-".into()),
+        };
+
+        format!(
+            "{}   {}     {}      {}      {}   {part_1:>10}  {part_2:>10}",
+            self.day,
+            check(self.scaffolded),
+            input,
+            tests,
+            stars
+        )
+    }
+}
+
+/// Everything shown on screen.
+struct State {
+    year: Option<u16>,
+    days: Vec<DayStatus>,
+    selected: usize,
+    pane_title: String,
+    pane: Vec<String>,
+    /// Number of lines the pane is scrolled up from the bottom.
+    scroll: usize,
+    running: Option<(Day, Job)>,
+}
+
+impl State {
+    fn load() -> Self {
+        let progress = Progress::load().unwrap_or_default();
+        // start with the timings of the last bench report, a bench in the dashboard replaces them.
+        let last_run = Run::load_last().ok().flatten().unwrap_or_default();
+
+        Self {
+            year: aoc_cli::get_year(),
+            days: all_days()
+                .map(|day| DayStatus {
+                    timings: DayStatus::last_timings(&last_run, day),
+                    ..DayStatus::load(day, &progress)
+                })
+                .collect(),
+            selected: 0,
+            pane_title: "Output".into(),
+            pane: vec![],
+            scroll: 0,
+            running: None,
+        }
+    }
+
+    fn selected_day(&self) -> Day {
+        self.days[self.selected].day
+    }
+
+    fn total_stars(&self) -> usize {
+        self.days.iter().map(|d| d.stars).sum()
+    }
+
+    fn show(&mut self, title: String, lines: Vec<String>) {
+        self.pane_title = title;
+        self.pane = lines;
+        self.scroll = 0;
+    }
+
+    fn push_line(&mut self, line: &str) {
+        // the runner overwrites intermediate results with a carriage return.
+        let line = line.rsplit('\r').next().unwrap_or_default();
+        self.pane.push(strip_ansi(line));
+        if self.scroll > 0 {
+            // keep the visible lines in place while scrolled up.
+            self.scroll += 1;
+        }
+    }
+
+    fn finish(&mut self, success: bool) {
+        let Some((day, job)) = self.running.take() else {
+            return;
+        };
+
+        // a test run that found no tests checked nothing.
+        let no_tests = job == Job::Tests && success && passed_tests(&self.pane) == Some(0);
+        let success = success && !no_tests;
+
+        let status = &mut self.days[usize::from(day.into_inner() - 1)];
+        match job {
+            Job::Tests => status.tests = Some(success),
+            Job::Bench if success => status.timings = Some(parse_exec_time(&self.pane, day)),
+            Job::Run | Job::Bench => {}
+        }
+
+        // the run might have downloaded an input or recorded a star.
+        let progress = Progress::load().unwrap_or_default();
+        let tests = status.tests;
+        let timings = status.timings.take();
+        *status = DayStatus {
+            tests,
+            timings,
+            ..DayStatus::load(day, &progress)
+        };
+
+        let result = if success { "✔ done" } else { "✖ failed" };
+        self.pane.push(String::new());
+        if no_tests {
+            self.pane
+                .push("no tests ran, add tests against the example input".into());
+        }
+        self.pane
+            .push(format!("{result}: {} of day {day}", job.label()));
+    }
+
+    /// Rows left for the pane below the title, the calendar, the pane title and the key help.
+    fn pane_height(&self, height: usize) -> usize {
+        height.saturating_sub(self.days.len() + 4)
+    }
+
+    /// Largest scroll offset that still fills the pane.
+    fn max_scroll(&self) -> usize {
+        let height = terminal::size().map_or(0, |(_, h)| usize::from(h));
+        self.pane.len().saturating_sub(self.pane_height(height))
+    }
+
+    /// Lay out the screen as lines of text.
+    fn render(&self, width: usize, height: usize) -> Vec<String> {
+        let title = match self.year {
+            Some(year) => format!("🎄 Advent of Code {year}"),
+            None => "🎄 Advent of Code".into(),
+        };
+
+        let mut lines = vec![
+            format!("{title}  ·  Stars: {}/50", self.total_stars()),
+            "    Day  Code  Input  Tests  Stars      Part 1      Part 2".into(),
+        ];
+
+        for (i, status) in self.days.iter().enumerate() {
+            let marker = if i == self.selected { " > " } else { "   " };
+            lines.push(format!("{marker} {}", status.row()));
+        }
+
+        let title = match self.running {
+            Some((day, job)) => format!(
+                "{} (running {} of day {day}…)",
+                self.pane_title,
+                job.label()
+            ),
+            None => self.pane_title.clone(),
+        };
+        lines.push(
+            format!("── {title} ")
+                .chars()
+                .chain(std::iter::repeat('─'))
+                .take(width)
+                .collect(),
+        );
+
+        // the pane takes the remaining rows, keeping one for the key help.
+        let pane_height = self.pane_height(height);
+        let end = self.pane.len().saturating_sub(self.scroll);
+        let start = end.saturating_sub(pane_height);
+        lines.extend(self.pane[start..end].iter().cloned());
+        lines.resize(height.saturating_sub(1).max(lines.len()), String::new());
+        lines.push(KEY_HELP.into());
+
+        lines
+            .into_iter()
+            .map(|line| line.chars().take(width).collect())
+            .collect()
+    }
+}
+
+/// Run a cargo command in the background and stream its output to the dashboard.
+fn spawn_job(day: Day, job: Job, sender: Sender<Message>) -> io::Result<()> {
+    let mut child = Command::new("cargo")
+        .args(job.cargo_args(day))
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    let forward = |stream: Box<dyn Read + Send>, sender: Sender<Message>| {
+        thread::spawn(move || {
+            for line in BufReader::new(stream).lines().map_while(Result::ok) {
+                let _ = sender.send(Message::Line(line));
+            }
+        })
+    };
+
+    let stdout = forward(Box::new(child.stdout.take().unwrap()), sender.clone());
+    let stderr = forward(Box::new(child.stderr.take().unwrap()), sender.clone());
+
+    thread::spawn(move || {
+        let _ = stdout.join();
+        let _ = stderr.join();
+        let success = child.wait().is_ok_and(|status| status.success());
+        let _ = sender.send(Message::Done(success));
+    });
+
+    Ok(())
+}
+
+fn open_puzzle(state: &mut State) {
+    let day = state.selected_day();
+    let path = config::get().puzzle_path(day);
+
+    let width = terminal::size().map_or(80, |(w, _)| usize::from(w));
+    let lines = match fs::read_to_string(&path) {
+        // styles are dropped, the screen is cut to width by characters.
+        Ok(puzzle) => markdown::render(&puzzle, width)
+            .lines()
+            .map(strip_ansi)
+            .collect(),
+        Err(_) => vec![format!(
+            "No puzzle description at \"{}\", run \"cargo download {day}\".",
+            path.display()
+        )],
+    };
+
+    state.show(format!("Puzzle {day}"), lines);
+    // start at the top of the description.
+    state.scroll = state.max_scroll();
+}
+
+/// Restores the terminal when the dashboard exits, also on panics.
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+        Ok(Self)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn draw(state: &State) -> io::Result<()> {
+    let (width, height) = terminal::size()?;
+    let mut stdout = io::stdout();
+
+    for (row, line) in state
+        .render(usize::from(width), usize::from(height))
+        .iter()
+        .enumerate()
+    {
+        let row = u16::try_from(row).unwrap_or(u16::MAX);
+        queue!(
+            stdout,
+            MoveTo(0, row),
+            Print(line),
+            Clear(ClearType::UntilNewLine)
+        )?;
+    }
+
+    stdout.flush()
+}
+
+/// Handle a key press, returns `false` if the dashboard should exit.
+fn handle_key(key: KeyEvent, state: &mut State, sender: &Sender<Message>) -> bool {
+    let page = usize::from(terminal::size().map_or(10, |(_, h)| h / 2));
+
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Esc => return false,
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return false,
+        KeyCode::Up | KeyCode::Char('k') => state.selected = state.selected.saturating_sub(1),
+        KeyCode::Down | KeyCode::Char('j') => {
+            state.selected = (state.selected + 1).min(state.days.len() - 1);
+        }
+        KeyCode::PageUp => state.scroll = (state.scroll + page).min(state.max_scroll()),
+        KeyCode::PageDown => state.scroll = state.scroll.saturating_sub(page),
+        KeyCode::Char('p') => open_puzzle(state),
+        KeyCode::Char(c @ ('t' | 'r' | 'b')) if state.running.is_none() => {
+            let job = match c {
+                't' => Job::Tests,
+                'r' => Job::Run,
+                _ => Job::Bench,
+            };
+            let day = state.selected_day();
+            state.show(format!("cargo {}", job.cargo_args(day).join(" ")), vec![]);
+
+            match spawn_job(day, job, sender.clone()) {
+                Ok(()) => state.running = Some((day, job)),
+                Err(e) => state.pane.push(format!("✖ could not call cargo: {e}")),
+            }
+        }
+        _ => {}
+    }
+
+    true
+}
+
+fn run(
+    state: &mut State,
+    sender: &Sender<Message>,
+    receiver: &Receiver<Message>,
+) -> io::Result<()> {
+    let _guard = TerminalGuard::enter()?;
+
+    loop {
+        while let Ok(message) = receiver.try_recv() {
+            match message {
+                Message::Line(line) => state.push_line(&line),
+                Message::Done(success) => state.finish(success),
+            }
+        }
+
+        draw(state)?;
+
+        if event::poll(TICK)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press && !handle_key(key, state, sender) {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+pub fn handle() {
+    let mut state = State::load();
+    let (sender, receiver) = mpsc::channel();
+
+    if let Err(e) = run(&mut state, &sender, &receiver) {
+        eprintln!("Dashboard failed: {e}");
+        process::exit(1);
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{DayStatus, InputStatus, Job, State, KEY_HELP};
+    use crate::template::bench_report::Run;
+    use crate::template::readme_benchmarks::{PartTiming, Timings};
+    use crate::{all_days, day};
+
+    fn get_mock_state() -> State {
+        State {
+            year: Some(2023),
+            days: all_days()
+                .map(|day| DayStatus {
+                    day,
+                    scaffolded: day == 1,
+                    input: InputStatus::Missing,
+                    tests: None,
+                    stars: 0,
+                    timings: None,
+                })
+                .collect(),
+            selected: 0,
+            pane_title: "Output".into(),
+            pane: (0..10).map(|i| format!("line {i}")).collect(),
+            scroll: 0,
+            running: None,
+        }
+    }
+
+    #[test]
+    fn renders_rows() {
+        let mut status = get_mock_state().days[0].clone();
+        status.input = InputStatus::Ok;
+        status.tests = Some(false);
+        status.stars = 1;
+        status.timings = Some(Timings {
+            part_1: Some(PartTiming {
+                duration: Duration::from_micros(12),
+                samples: 10,
+            }),
+            ..Timings::new(day!(1))
+        });
+
+        assert_eq!(
+            status.row(),
+            "01   ✔     ✔      ✖      ⭐ ·       12.0µs           -"
+        );
+    }
+
+    #[test]
+    fn loads_last_timings() {
+        let run = Run::parse("01 1 100 200 300\n02 2 50\n");
+
+        let timings = DayStatus::last_timings(&run, day!(1)).unwrap();
+        assert_eq!(
+            timings.part_1,
+            Some(PartTiming {
+                duration: Duration::from_nanos(200),
+                samples: 3,
+            })
+        );
+        assert_eq!(timings.part_2, None);
+        assert!(DayStatus::last_timings(&run, day!(2))
+            .unwrap()
+            .part_2
+            .is_some());
+        assert!(DayStatus::last_timings(&run, day!(3)).is_none());
+    }
+
+    #[test]
+    fn renders_screen() {
+        let mut state = get_mock_state();
+        state.selected = 2;
+        state.running = Some((day!(3), Job::Tests));
+
+        let lines = state.render(80, 32);
+        assert_eq!(lines.len(), 32);
+        assert_eq!(lines[0], "🎄 Advent of Code 2023  ·  Stars: 0/50");
+        assert!(lines[4].starts_with(" >  03"));
+        assert!(lines[27].starts_with("── Output (running example tests of day 03…) ──"));
+        // the pane shows the last lines that fit.
+        assert_eq!(lines[28..31], ["line 7", "line 8", "line 9"]);
+        assert_eq!(lines[31], KEY_HELP);
+
+        state.scroll = 2;
+        assert_eq!(state.render(80, 32)[28..31], ["line 5", "line 6", "line 7"]);
+    }
+
+    #[test]
+    fn fails_test_runs_without_tests() {
+        let mut state = get_mock_state();
+        state.show("Output".into(), vec![]);
+        state.running = Some((day!(1), Job::Tests));
+        state.push_line("running 0 tests");
+        state.push_line("test result: ok. 0 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s");
+        state.finish(true);
+
+        assert_eq!(state.days[0].tests, Some(false));
+        assert_eq!(
+            state.pane.last().unwrap(),
+            "✖ failed: example tests of day 01"
+        );
+    }
+
+    #[test]
+    fn strips_runner_output() {
+        let mut state = get_mock_state();
+        state.show("Output".into(), vec![]);
+        state.push_line("Part 1: \x1b[1m42\x1b[0m\rPart 1: \x1b[1m42\x1b[0m (1.2ms)");
+        assert_eq!(state.pane, ["Part 1: 42 (1.2ms)"]);
+    }
+}
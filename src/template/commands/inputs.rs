@@ -0,0 +1,181 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::Path,
+    process,
+    str::FromStr,
+};
+
+use crossterm::{
+    event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
+    terminal,
+};
+
+use crate::all_days;
+use crate::template::{
+    config,
+    encryption::{self, Secret},
+    input,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Encrypt,
+    Decrypt,
+}
+
+impl Action {
+    fn name(self) -> &'static str {
+        match self {
+            Action::Encrypt => "encrypt",
+            Action::Decrypt => "decrypt",
+        }
+    }
+}
+
+impl FromStr for Action {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "encrypt" => Ok(Action::Encrypt),
+            "decrypt" => Ok(Action::Decrypt),
+            _ => Err(format!(
+                "unknown action \"{s}\", expected encrypt or decrypt"
+            )),
+        }
+    }
+}
+
+/// Read a line from the terminal without echoing it.
+fn read_hidden(prompt: &str) -> io::Result<String> {
+    eprint!("{prompt}");
+    io::stderr().flush()?;
+
+    terminal::enable_raw_mode()?;
+    let mut line = String::new();
+
+    let result = loop {
+        match event::read() {
+            Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => match key.code {
+                KeyCode::Enter => break Ok(()),
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    break Err(io::ErrorKind::Interrupted.into());
+                }
+                KeyCode::Char(c) => line.push(c),
+                KeyCode::Backspace => {
+                    line.pop();
+                }
+                _ => {}
+            },
+            Ok(_) => {}
+            Err(e) => break Err(e),
+        }
+    };
+
+    terminal::disable_raw_mode()?;
+    eprintln!();
+    result.map(|()| line)
+}
+
+/// The key file passed on the command line, the configured secret, or a passphrase read from the terminal.
+fn resolve_secret(action: Action, key_file: Option<&Path>) -> io::Result<Secret> {
+    if let Some(path) = key_file {
+        return Secret::from_key_file(path);
+    }
+
+    if let Some(secret) = Secret::from_env()? {
+        return Ok(secret);
+    }
+
+    let passphrase = read_hidden("Passphrase: ")?;
+    if action == Action::Encrypt && read_hidden("Repeat passphrase: ")? != passphrase {
+        return Err(io::Error::other("the passphrases do not match"));
+    }
+
+    Ok(Secret::Passphrase(passphrase))
+}
+
+fn encrypt_all(secret: &Secret) -> io::Result<()> {
+    let config = config::get();
+    fs::create_dir_all(&config.paths.encrypted_inputs)?;
+    let mut written = 0;
+
+    for day in all_days() {
+        let plaintext = match fs::read(config.input_path(day)) {
+            Ok(plaintext) if !plaintext.is_empty() => plaintext,
+            _ => continue,
+        };
+
+        // re-encrypting changes salt and nonce, skip unchanged inputs to keep the history clean.
+        let path = encryption::encrypted_path(day);
+        let unchanged = fs::read(&path)
+            .ok()
+            .and_then(|data| encryption::decrypt(&data, secret, day).ok())
+            .is_some_and(|existing| existing == plaintext);
+
+        if !unchanged {
+            fs::write(&path, encryption::encrypt(&plaintext, secret, day))?;
+            println!("Encrypted input of day {day} to \"{}\".", path.display());
+            written += 1;
+        }
+    }
+
+    println!("🎄 {written} input(s) encrypted, the others are up to date.");
+    Ok(())
+}
+
+fn decrypt_all(secret: &Secret) -> Result<(), encryption::Error> {
+    let config = config::get();
+    fs::create_dir_all(&config.paths.inputs)?;
+    let mut written = 0;
+
+    for day in all_days() {
+        let Ok(data) = fs::read(encryption::encrypted_path(day)) else {
+            continue;
+        };
+
+        let plaintext = encryption::decrypt(&data, secret, day)?;
+        let path = config.input_path(day);
+
+        match fs::read(&path) {
+            Ok(existing) if existing == plaintext => continue,
+            Ok(existing) if !existing.is_empty() => {
+                eprintln!(
+                    "Skipped day {day}, \"{}\" differs from the encrypted copy.",
+                    path.display()
+                );
+                continue;
+            }
+            _ => {}
+        }
+
+        fs::write(&path, plaintext)?;
+        input::record_checksum(&path)?;
+        println!("Decrypted input of day {day} to \"{}\".", path.display());
+        written += 1;
+    }
+
+    println!("🎄 {written} input(s) decrypted.");
+    Ok(())
+}
+
+pub fn handle(action: Action, key_file: Option<&Path>) {
+    let secret = match resolve_secret(action, key_file) {
+        Ok(secret) => secret,
+        Err(e) => {
+            eprintln!("Failed to read the key: {e}");
+            process::exit(1);
+        }
+    };
+
+    let result = match action {
+        Action::Encrypt => encrypt_all(&secret).map_err(encryption::Error::from),
+        Action::Decrypt => decrypt_all(&secret),
+    };
+
+    if let Err(e) = result {
+        eprintln!("Failed to {} inputs: {e}", action.name());
+        process::exit(1);
+    }
+}
@@ -1,5 +1,14 @@
 pub mod all;
+pub mod bench_report;
+pub mod completions;
+pub mod countdown;
+pub mod dashboard;
 pub mod download;
+pub mod inputs;
+pub mod progress;
 pub mod read;
 pub mod scaffold;
+pub mod search;
 pub mod solve;
+pub mod stats;
+pub mod watch;
@@ -0,0 +1,31 @@
+use std::process;
+
+use crate::template::{aoc_cli, progress::Progress, readme_stars, ANSI_BOLD, ANSI_RESET};
+
+pub fn handle(update_readme: bool) {
+    let progress = match Progress::load() {
+        Ok(progress) => progress,
+        Err(e) => {
+            eprintln!("Failed to read progress: {e}");
+            process::exit(1);
+        }
+    };
+
+    let year = aoc_cli::get_year();
+
+    match year {
+        Some(year) => println!("{ANSI_BOLD}🎄 Advent of Code {year}{ANSI_RESET}"),
+        None => println!("{ANSI_BOLD}🎄 Advent of Code{ANSI_RESET}"),
+    }
+    println!();
+    println!("{}", readme_stars::render_calendar(&progress));
+
+    if update_readme {
+        match readme_stars::update(&progress, year) {
+            Ok(()) => println!("Successfully updated README with stars."),
+            Err(_) => {
+                eprintln!("Failed to update readme with stars.");
+            }
+        }
+    }
+}
@@ -1,9 +1,43 @@
-use std::process;
+use std::{
+    env, fs,
+    io::{stdout, IsTerminal, Write},
+    process::{self, Command, Stdio},
+};
 
-use crate::template::aoc_cli;
+use crossterm::terminal;
+
+use crate::template::{aoc_cli, config, markdown};
 use crate::Day;
 
-pub fn handle(day: Day) {
+const DEFAULT_WIDTH: usize = 80;
+
+pub fn handle(day: Day, part: Option<u8>) {
+    let path = config::get().puzzle_path(day);
+
+    // read the puzzle offline once it was downloaded.
+    if let Ok(puzzle) = fs::read_to_string(&path) {
+        let section = match part {
+            Some(part) => markdown::select_part(&puzzle, part),
+            None => Some(puzzle.as_str()),
+        };
+
+        let Some(section) = section else {
+            eprintln!("Part two of day {day} is not unlocked in \"{}\". Solve part one and run \"cargo download {day}\" again.", path.display());
+            process::exit(1);
+        };
+
+        page(&markdown::render(section, width()));
+        return;
+    }
+
+    if part.is_some() {
+        eprintln!(
+            "No puzzle description at \"{}\", run \"cargo download {day}\" first.",
+            path.display()
+        );
+        process::exit(1);
+    }
+
     if aoc_cli::check().is_err() {
         eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
         process::exit(1);
@@ -14,3 +48,37 @@ pub fn handle(day: Day) {
         process::exit(1);
     };
 }
+
+/// Width of the terminal the description is shown in, `DEFAULT_WIDTH` when piped.
+fn width() -> usize {
+    if !stdout().is_terminal() {
+        return DEFAULT_WIDTH;
+    }
+    terminal::size().map_or(DEFAULT_WIDTH, |(w, _)| usize::from(w))
+}
+
+/// Show text through `$PAGER` (default `less -R`) when attached to a terminal, print it otherwise.
+fn page(text: &str) {
+    if stdout().is_terminal() {
+        let pager = env::var("PAGER").unwrap_or_else(|_| "less -R".into());
+        let mut parts = pager.split_whitespace();
+
+        if let Some(program) = parts.next() {
+            let child = Command::new(program)
+                .args(parts)
+                .stdin(Stdio::piped())
+                .spawn();
+
+            if let Ok(mut child) = child {
+                if let Some(mut stdin) = child.stdin.take() {
+                    // the pager may exit before reading everything.
+                    let _ = writeln!(stdin, "{text}");
+                }
+                let _ = child.wait();
+                return;
+            }
+        }
+    }
+
+    println!("{text}");
+}
@@ -1,12 +1,15 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
+use crate::template::config;
 use crate::Day;
 
-const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(DAY_NUMBER);
+const MODULE_TEMPLATE: &str = r#"/// The day of this solution, used to read its example files.
+pub const DAY: crate::Day = crate::day!(DAY_NUMBER);
 
 pub fn part_one(input: &str) -> Option<u32> {
     None
@@ -22,18 +25,24 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&crate::template::read_file("examples", DAY, 1));
         assert_eq!(result, None);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&crate::template::read_file("examples", DAY, 1));
         assert_eq!(result, None);
     }
 }
 "#;
 
+/// Binary of a day, a thin wrapper running the parts of its library module `MODULE`.
+const BIN_TEMPLATE: &str = r#"use advent_of_code::MODULE::{part_one, part_two};
+
+advent_of_code::solution!(DAY_NUMBER);
+"#;
+
 fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
 }
@@ -42,32 +51,129 @@ fn create_file(path: &str) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create(true).open(path)
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// The solution template, read from the file configured in `[templates]` if there is one.
+fn read_template() -> String {
+    let Some(path) = &config::get().templates.solution else {
+        return MODULE_TEMPLATE.into();
+    };
+
+    match fs::read_to_string(path) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!("Failed to read template \"{}\": {e}", path.display());
+            process::exit(1);
+        }
+    }
+}
+
+/// Insert `pub mod <name>;` into the `pub mod` declarations of a module, keeping them sorted.
+/// Returns `None` if the module is declared already.
+fn declare_module(contents: &str, name: &str) -> Option<String> {
+    let declaration = format!("pub mod {name};");
+    let mut lines: Vec<&str> = contents.lines().collect();
+    if lines.contains(&declaration.as_str()) {
+        return None;
+    }
+
+    let is_declaration = |l: &&str| l.starts_with("pub mod ");
+    let index = lines
+        .iter()
+        .position(|l| is_declaration(l) && *l > declaration.as_str())
+        .or_else(|| lines.iter().rposition(is_declaration).map(|i| i + 1))
+        .unwrap_or(lines.len());
+    lines.insert(index, &declaration);
+
+    Some(lines.join("\n") + "\n")
+}
 
-    let mut file = match safe_create_file(&module_path) {
+/// Declare a module in its parent, e.g. `pub mod day05;` in `y2023/mod.rs`.
+fn add_module(parent: &Path, name: &str) -> Result<(), std::io::Error> {
+    let contents = fs::read_to_string(parent).unwrap_or_default();
+    if let Some(contents) = declare_module(&contents, name) {
+        fs::write(parent, contents)?;
+        println!("Added `pub mod {name};` to \"{}\"", parent.display());
+    }
+    Ok(())
+}
+
+fn write_file(path: &Path, contents: &str, kind: &str) {
+    let mut file = match safe_create_file(&path.display().to_string()) {
         Ok(file) => file,
         Err(e) => {
-            eprintln!("Failed to create module file: {e}");
+            eprintln!("Failed to create {kind} file: {e}");
             process::exit(1);
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("DAY_NUMBER", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    match file.write_all(contents.as_bytes()) {
         Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Created {kind} file \"{}\"", path.display());
         }
         Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
+            eprintln!("Failed to write {kind} contents: {e}");
             process::exit(1);
         }
     }
+}
+
+pub fn handle(day: Day) {
+    let config = config::get();
+    let input_path = config.input_path(day).display().to_string();
+    // the example of part one, read by the tests of the template.
+    let example_path = config
+        .paths
+        .examples
+        .join(format!("{day}-1.txt"))
+        .display()
+        .to_string();
+    let (Some(module_path), Some(module)) = (config.module_path(day), config.test_filter(day))
+    else {
+        eprintln!("Set `year` in aoc.toml or AOC_YEAR to scaffold a day.");
+        process::exit(1);
+    };
+    let day_number = day.into_inner().to_string();
+
+    let year_dir = module_path.parent().unwrap_or(&config.paths.solutions);
+    let year_mod = year_dir.join("mod.rs");
+    if !year_mod.exists() {
+        if let Err(e) = fs::create_dir_all(year_dir) {
+            eprintln!("Failed to create \"{}\": {e}", year_dir.display());
+            process::exit(1);
+        }
+        // a new year also needs to be declared in the library.
+        let year = year_dir.file_name().unwrap_or_default().to_string_lossy();
+        let lib = config.paths.solutions.join("lib.rs");
+        if let Err(e) = add_module(&lib, &year) {
+            eprintln!("Failed to add the module to \"{}\": {e}", lib.display());
+            process::exit(1);
+        }
+    }
+
+    write_file(
+        &module_path,
+        &read_template().replace("DAY_NUMBER", &day_number),
+        "module",
+    );
+
+    let name = module_path
+        .file_stem()
+        .unwrap_or_default()
+        .to_string_lossy();
+    if let Err(e) = add_module(&year_mod, &name) {
+        eprintln!(
+            "Failed to add the module to \"{}\": {e}",
+            year_mod.display()
+        );
+        process::exit(1);
+    }
+
+    write_file(
+        &config.bin_path(day),
+        &BIN_TEMPLATE
+            .replace("MODULE", &module)
+            .replace("DAY_NUMBER", &day_number),
+        "binary",
+    );
 
     match create_file(&input_path) {
         Ok(_) => {
@@ -92,3 +198,31 @@ pub fn handle(day: Day) {
     println!("---");
     println!("🎄 Type `cargo solve {}` to run your solution.", day);
 }
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::declare_module;
+
+    #[test]
+    fn declares_modules_in_order() {
+        let contents = "/// Solutions.\npub mod day01;\npub mod day03;\n";
+        assert_eq!(
+            declare_module(contents, "day02").unwrap(),
+            "/// Solutions.\npub mod day01;\npub mod day02;\npub mod day03;\n"
+        );
+        assert_eq!(
+            declare_module(contents, "day04").unwrap(),
+            "/// Solutions.\npub mod day01;\npub mod day03;\npub mod day04;\n"
+        );
+        assert_eq!(declare_module(contents, "day03"), None);
+        assert_eq!(
+            declare_module(
+                "mod day;\npub mod template;\npub mod y2023;\n\npub use day::*;\n",
+                "y2024"
+            )
+            .unwrap(),
+            "mod day;\npub mod template;\npub mod y2023;\npub mod y2024;\n\npub use day::*;\n"
+        );
+        assert_eq!(declare_module("", "day01").unwrap(), "pub mod day01;\n");
+    }
+}
@@ -0,0 +1,43 @@
+use std::process;
+
+use crate::template::{config, search::Index, tags::Tags, ANSI_BOLD, ANSI_DIM, ANSI_RESET};
+
+pub fn handle(terms: &[String], filter: &[String]) {
+    if terms.is_empty() && filter.is_empty() {
+        eprintln!("Nothing to search for, pass search terms or \"--tag\".");
+        process::exit(1);
+    }
+
+    let tags = match Tags::load() {
+        Ok(tags) => tags,
+        Err(e) => {
+            eprintln!("Failed to read tags: {e}");
+            process::exit(1);
+        }
+    };
+
+    let hits = Index::load(config::get(), tags).search(terms, filter);
+
+    if hits.is_empty() {
+        println!("No matching days.");
+        return;
+    }
+
+    for (i, hit) in hits.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+
+        let tags = if hit.tags.is_empty() {
+            String::new()
+        } else {
+            format!(" {ANSI_DIM}[{}]{ANSI_RESET}", hit.tags.join(", "))
+        };
+        println!("{ANSI_BOLD}Day {}{ANSI_RESET}{tags}", hit.day);
+
+        for snippet in &hit.snippets {
+            let location = format!("{}:{}", snippet.source.name(), snippet.line);
+            println!("  {ANSI_DIM}{location:<12}{ANSI_RESET} {}", snippet.text);
+        }
+    }
+}
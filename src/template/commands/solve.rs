@@ -1,8 +1,9 @@
-use std::process::{Command, Stdio};
+use std::process::{self, Command, Stdio};
 
+use crate::template::{cli::SolutionArgs, config};
 use crate::Day;
 
-pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
+pub fn handle(day: Day, release: bool, solution_args: SolutionArgs) {
     let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
 
     if release {
@@ -10,22 +11,25 @@ pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
     }
 
     cmd_args.push("--".to_string());
+    cmd_args.extend(solution_args.to_args());
 
-    if let Some(submit_part) = submit_part {
-        cmd_args.push("--submit".to_string());
-        cmd_args.push(submit_part.to_string());
-    }
+    let mut cmd = Command::new("cargo");
+    cmd.args(&cmd_args);
 
-    if time {
-        cmd_args.push("--time".to_string());
+    // pass a year from `--year` on to the solution.
+    if let Some(year) = config::get().year {
+        cmd.env("AOC_YEAR", year.to_string());
     }
 
-    let mut cmd = Command::new("cargo")
-        .args(&cmd_args)
+    let mut cmd = cmd
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
         .spawn()
         .unwrap();
 
-    cmd.wait().unwrap();
+    // pass a failing solution, e.g. disagreeing variants, on as our exit code.
+    let status = cmd.wait().unwrap();
+    if !status.success() {
+        process::exit(status.code().unwrap_or(1));
+    }
 }
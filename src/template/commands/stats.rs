@@ -0,0 +1,48 @@
+use std::process;
+
+use crate::template::{
+    readme_solve_times::{self, format_times},
+    solve_log::SolveLog,
+    ANSI_BOLD, ANSI_RESET,
+};
+
+pub fn handle(update_readme: bool) {
+    let log = match SolveLog::load() {
+        Ok(log) => log,
+        Err(e) => {
+            eprintln!("Failed to read solve log: {e}");
+            process::exit(1);
+        }
+    };
+
+    let stats = log.all_stats();
+
+    if stats.is_empty() {
+        println!(
+            "No solve times recorded yet. Times are tracked from `cargo download` to `--submit`."
+        );
+        return;
+    }
+
+    println!(
+        "{ANSI_BOLD}{:<6} {:>12} {:>12} {:>6}{ANSI_RESET}",
+        "Day", "Part 1", "Part 2", "Wrong"
+    );
+    for (day, day_stats) in &stats {
+        let (part_1, part_2) = format_times(day_stats);
+        println!(
+            "{:<6} {part_1:>12} {part_2:>12} {:>6}",
+            day.to_string(),
+            day_stats.wrong_attempts
+        );
+    }
+
+    if update_readme {
+        match readme_solve_times::update(&stats) {
+            Ok(()) => println!("Successfully updated README with solve times."),
+            Err(_) => {
+                eprintln!("Failed to update readme with solve times.");
+            }
+        }
+    }
+}
@@ -0,0 +1,273 @@
+/// Re-runs the example tests and the real input of a day whenever one of its files changes.
+/// Changes are detected by polling modification times, so no file system service is required.
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::{config, strip_ansi, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::Day;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// Local crates whose sources are watched in addition to the day's files.
+const LOCAL_CRATES: [&str; 2] = ["mygrid/src", "bitvec/src"];
+
+pub fn handle(day: Day, release: bool) {
+    let mut snapshot = HashMap::new();
+    let mut answers: HashMap<u8, String> = HashMap::new();
+
+    loop {
+        let current = take_snapshot(day);
+
+        if current != snapshot {
+            snapshot = current;
+            print!("{CLEAR_SCREEN}");
+            println!("{ANSI_BOLD}Day {day}{ANSI_RESET} {ANSI_ITALIC}(watching {} files, ctrl+c to exit){ANSI_RESET}", snapshot.len());
+            println!("------");
+
+            if run_examples(day, release) {
+                run_input(day, release, &mut answers);
+            }
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn get_watched_paths(day: Day) -> Vec<PathBuf> {
+    // examples are stored as `DD.txt` or `DD-N.txt`.
+    let config = config::get();
+    let mut paths = vec![config.bin_path(day)];
+
+    if let Ok(entries) = fs::read_dir(&config.paths.examples) {
+        let prefix = day.to_string();
+        paths.extend(
+            entries
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| {
+                    path.file_name()
+                        .and_then(|name| name.to_str())
+                        .is_some_and(|name| name.starts_with(&prefix))
+                }),
+        );
+    }
+
+    paths.push(config.input_path(day));
+    paths.extend(config.module_path(day));
+
+    for dir in LOCAL_CRATES {
+        collect_files(Path::new(dir), &mut paths);
+    }
+
+    paths
+}
+
+fn collect_files(dir: &Path, paths: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for path in entries.filter_map(Result::ok).map(|entry| entry.path()) {
+        if path.is_dir() {
+            collect_files(&path, paths);
+        } else {
+            paths.push(path);
+        }
+    }
+}
+
+fn take_snapshot(day: Day) -> HashMap<PathBuf, SystemTime> {
+    get_watched_paths(day)
+        .into_iter()
+        .filter_map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+            Some((path, modified))
+        })
+        .collect()
+}
+
+fn cargo(args: &[&str], release: bool) -> Option<(bool, String, String)> {
+    let mut args = args.to_vec();
+    if release {
+        args.push("--release");
+    }
+
+    let output = Command::new("cargo")
+        .args(&args)
+        .stdin(Stdio::null())
+        .output()
+        .ok()?;
+
+    Some((
+        output.status.success(),
+        String::from_utf8_lossy(&output.stdout).into_owned(),
+        String::from_utf8_lossy(&output.stderr).into_owned(),
+    ))
+}
+
+/// Arguments of `cargo test` running the example tests of a day, which live in its library module.
+/// Without a year, the filter matches the day's module of every year.
+pub(crate) fn test_args(day: Day) -> Vec<String> {
+    let filter = config::get()
+        .test_filter(day)
+        .unwrap_or_else(|| format!("day{day}"));
+    vec!["test".into(), "--quiet".into(), "--lib".into(), filter]
+}
+
+/// Number of passed tests in the `test result:` summaries of `cargo test`, `None` without a summary.
+pub(crate) fn passed_tests<S: AsRef<str>>(output: &[S]) -> Option<usize> {
+    output
+        .iter()
+        .filter_map(|line| line.as_ref().strip_prefix("test result:"))
+        // e.g. ` ok. 2 passed; 0 failed; 0 ignored; ...`.
+        .map(|summary| {
+            summary
+                .split("; ")
+                .find_map(|count| {
+                    count
+                        .strip_suffix(" passed")?
+                        .rsplit(' ')
+                        .next()?
+                        .parse()
+                        .ok()
+                })
+                .unwrap_or(0)
+        })
+        .reduce(|a, b| a + b)
+}
+
+/// Run the unit tests of the day and print a one line summary, followed by failure details.
+/// Returns `true` if all tests passed and there was at least one.
+fn run_examples(day: Day, release: bool) -> bool {
+    let args = test_args(day);
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    let Some((success, stdout, stderr)) = cargo(&args, release) else {
+        eprintln!("Examples: ✖ could not call cargo.");
+        return false;
+    };
+
+    let Some(summary) = stdout.lines().find(|l| l.starts_with("test result:")) else {
+        // the tests did not compile, show the compiler output.
+        println!("Examples: ✖ build failed");
+        eprintln!("{stderr}");
+        return false;
+    };
+
+    // e.g. `test result: ok. 2 passed; 0 failed; 0 ignored; ...` becomes `2 passed, 0 failed`.
+    let counts = summary
+        .split_once(". ")
+        .map_or_else(String::new, |(_, counts)| {
+            counts.split("; ").take(2).collect::<Vec<_>>().join(", ")
+        });
+
+    let lines: Vec<&str> = stdout.lines().collect();
+    if success && passed_tests(&lines) == Some(0) {
+        println!("Examples: ✖ no tests ran, add tests against the example input");
+        return false;
+    }
+
+    if success {
+        println!("Examples: ✔ {counts}");
+        return true;
+    }
+
+    println!("Examples: ✖ {counts}");
+    stdout
+        .lines()
+        .filter(|l| {
+            l.starts_with("---- ")
+                || l.contains("panicked at")
+                || l.trim_start().starts_with("left:")
+                || l.trim_start().starts_with("right:")
+        })
+        .for_each(|l| println!("  {l}"));
+
+    false
+}
+
+/// Run the day against its real input and print the answers, compared to the previous run.
+fn run_input(day: Day, release: bool, answers: &mut HashMap<u8, String>) {
+    let day_padded = day.to_string();
+
+    let Some((success, stdout, stderr)) = cargo(&["run", "--quiet", "--bin", &day_padded], release)
+    else {
+        eprintln!("Input: ✖ could not call cargo.");
+        return;
+    };
+
+    for (part, answer) in parse_answers(&stdout) {
+        let diff = match answers.insert(part, answer.clone()) {
+            None => String::new(),
+            Some(previous) if previous == answer => " (unchanged)".into(),
+            Some(previous) => format!(" (was {previous})"),
+        };
+        println!("Part {part}: {ANSI_BOLD}{answer}{ANSI_RESET}{diff}");
+    }
+
+    if !success {
+        println!("Input: ✖ solution exited with an error");
+        eprintln!("{stderr}");
+    }
+}
+
+/// Extract the answers from the runner output, e.g. `Part 1: 42 (1.2ms)`.
+fn parse_answers(output: &str) -> Vec<(u8, String)> {
+    output
+        .lines()
+        // the runner overwrites intermediate results with a carriage return.
+        .filter_map(|l| l.rsplit('\r').next())
+        .map(strip_ansi)
+        .filter_map(|l| {
+            let rest = l.strip_prefix("Part ")?;
+            let (part, rest) = rest.split_once(": ")?;
+            let answer = rest.rsplit_once(" (").map_or(rest, |(answer, _)| answer);
+            Some((part.parse().ok()?, answer.trim().to_string()))
+        })
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_answers, passed_tests};
+
+    #[test]
+    fn test_passed_tests() {
+        let output = [
+            "running 2 tests",
+            "test result: ok. 2 passed; 0 failed; 0 ignored; 0 measured; 88 filtered out; finished in 0.00s",
+        ];
+        assert_eq!(passed_tests(&output), Some(2));
+        assert_eq!(
+            passed_tests(&["test result: ok. 0 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s"]),
+            Some(0)
+        );
+        assert_eq!(passed_tests(&["error: could not compile"]), None);
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        let output = [
+            "Part 1: \x1b[1m42\x1b[0m\rPart 1: \x1b[1m42\x1b[0m (1.2ms)",
+            "Part 2: ✖\rPart 2: ✖             ",
+            "Part 2: ▼ (3.0µs)",
+        ]
+        .join("\n");
+
+        assert_eq!(
+            parse_answers(&output),
+            vec![
+                (1, "42".to_string()),
+                (2, "✖".to_string()),
+                (2, "▼".to_string())
+            ]
+        );
+    }
+}
@@ -0,0 +1,531 @@
+/// Project configuration, read once from `aoc.toml` in the project root.
+/// Every setting has a default, so the file and each of its keys are optional.
+/// The `AOC_CONFIG`, `AOC_YEAR` and `AOC_CLI` environment variables and the `--year` flag take precedence over the file.
+///
+/// Only the subset of TOML needed here is supported: `[section]` headers and `key = value` pairs with
+/// string, integer, float or boolean values.
+use std::{
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+    process,
+    sync::OnceLock,
+    time::Duration,
+};
+
+use crate::Day;
+
+pub const CONFIG_PATH: &str = "aoc.toml";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    /// Year passed to aoc-cli, the current event is used if unset.
+    pub year: Option<u16>,
+    /// Command aoc-cli is called with, split on whitespace like `$PAGER`.
+    pub aoc_cli: String,
+    pub paths: Paths,
+    pub bench: Bench,
+    pub timeouts: Timeouts,
+    pub templates: Templates,
+    pub encryption: Encryption,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Paths {
+    pub inputs: PathBuf,
+    pub examples: PathBuf,
+    pub puzzles: PathBuf,
+    /// Encrypted copies of the inputs, written by `inputs encrypt`.
+    pub encrypted_inputs: PathBuf,
+    /// Directory of the solution binaries. Cargo only discovers binaries in `src/bin` on its own,
+    /// other directories need a `[[bin]]` entry per day in `Cargo.toml`.
+    pub bins: PathBuf,
+    /// Root of the library modules the bins wrap, holding `y<year>/day<day>.rs`.
+    /// Other directories need a `#[path]` attribute on the year module in `lib.rs`.
+    pub solutions: PathBuf,
+    pub readme: PathBuf,
+    /// Local store of solved parts, see `progress`.
+    pub progress: PathBuf,
+    /// Topic tags per day, maintained by hand.
+    pub tags: PathBuf,
+    /// Notes per day for the benchmark table, maintained by hand.
+    pub notes: PathBuf,
+    /// Log of opened puzzles and submissions, see `stats`.
+    pub solve_log: PathBuf,
+}
+
+/// Budget of the runner when benching a part with `--time`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bench {
+    /// Approximate time spent benching a part.
+    pub budget: Duration,
+    pub min_samples: u128,
+    pub max_samples: u128,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Timeouts {
+    /// Time after which `all` stops waiting for a solution and kills it.
+    pub solution: Option<Duration>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Templates {
+    /// File used by `scaffold` instead of the built-in library module template.
+    /// `DAY_NUMBER` is replaced with the day.
+    pub solution: Option<PathBuf>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Encryption {
+    /// File the key of the encrypted inputs is derived from, used instead of a passphrase.
+    pub key_file: Option<PathBuf>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            year: None,
+            aoc_cli: "aoc".into(),
+            paths: Paths {
+                inputs: "data/inputs".into(),
+                examples: "data/examples".into(),
+                puzzles: "data/puzzles".into(),
+                encrypted_inputs: "data/inputs-encrypted".into(),
+                bins: "src/bin".into(),
+                solutions: "src".into(),
+                readme: "README.md".into(),
+                progress: "data/progress.txt".into(),
+                tags: "data/tags.txt".into(),
+                notes: "data/notes.txt".into(),
+                solve_log: "data/solve_log.txt".into(),
+            },
+            bench: Bench {
+                budget: Duration::from_secs(1),
+                min_samples: 10,
+                max_samples: 10_000,
+            },
+            timeouts: Timeouts { solution: None },
+            templates: Templates { solution: None },
+            encryption: Encryption { key_file: None },
+        }
+    }
+}
+
+/// Settings passed on the command line, applied on top of the file and environment.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Overrides {
+    pub year: Option<u16>,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    IO(PathBuf, io::Error),
+    Parser { line: usize, message: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::IO(path, e) => write!(f, "could not read \"{}\": {e}", path.display()),
+            Error::Parser { line, message } => write!(f, "line {line}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Value {
+    String(String),
+    Integer(i64),
+    Float(f64),
+    Bool(bool),
+}
+
+impl Value {
+    fn parse(s: &str) -> Result<Self, String> {
+        if let Some(quoted) = s.strip_prefix('"') {
+            let Some(inner) = quoted.strip_suffix('"') else {
+                return Err(format!("unterminated string {s}"));
+            };
+            return Ok(Value::String(unescape(inner)?));
+        }
+
+        if let Some(quoted) = s.strip_prefix('\'') {
+            // literal strings have no escapes.
+            return quoted
+                .strip_suffix('\'')
+                .map(|inner| Value::String(inner.into()))
+                .ok_or_else(|| format!("unterminated string {s}"));
+        }
+
+        match s {
+            "true" => return Ok(Value::Bool(true)),
+            "false" => return Ok(Value::Bool(false)),
+            _ => {}
+        }
+
+        let number = s.replace('_', "");
+        if let Ok(integer) = number.parse() {
+            return Ok(Value::Integer(integer));
+        }
+        if let Ok(float) = number.parse() {
+            return Ok(Value::Float(float));
+        }
+
+        Err(format!("unsupported value {s}"))
+    }
+
+    fn type_name(&self) -> &'static str {
+        match self {
+            Value::String(_) => "a string",
+            Value::Integer(_) => "an integer",
+            Value::Float(_) => "a float",
+            Value::Bool(_) => "a boolean",
+        }
+    }
+}
+
+fn unescape(s: &str) -> Result<String, String> {
+    let mut result = String::with_capacity(s.len());
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('\\') => result.push('\\'),
+            Some('"') => result.push('"'),
+            Some('n') => result.push('\n'),
+            Some('t') => result.push('\t'),
+            other => {
+                return Err(format!(
+                    "unsupported escape sequence \\{}",
+                    other.unwrap_or(' ')
+                ))
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Remove a trailing comment, ignoring `#` inside of strings.
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+
+    for (i, c) in line.char_indices() {
+        match (quote, c) {
+            (None, '#') => return &line[..i],
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if q == c => quote = None,
+            _ => {}
+        }
+    }
+
+    line
+}
+
+impl Config {
+    /// Parse the contents of a config file on top of the defaults.
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let mut config = Self::default();
+        let mut section = String::new();
+        // line of the last sample bound, the bounds are checked once every key is applied.
+        let mut samples_line = 0;
+
+        for (i, raw) in s.lines().enumerate() {
+            let line = strip_comment(raw).trim();
+            let error = |message: String| Error::Parser {
+                line: i + 1,
+                message,
+            };
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(name) = line.strip_prefix('[') {
+                let name = name
+                    .strip_suffix(']')
+                    .ok_or_else(|| error(format!("invalid section header {line}")))?;
+                section = name.trim().to_string();
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| error(format!("expected `key = value`, found {line}")))?;
+            let value = Value::parse(value.trim()).map_err(&error)?;
+            let key = match section.as_str() {
+                "" => key.trim().to_string(),
+                section => format!("{section}.{}", key.trim()),
+            };
+
+            if matches!(key.as_str(), "bench.min_samples" | "bench.max_samples") {
+                samples_line = i + 1;
+            }
+            config.set(&key, value).map_err(error)?;
+        }
+
+        if config.bench.min_samples > config.bench.max_samples {
+            return Err(Error::Parser {
+                line: samples_line,
+                message: "bench.min_samples must not exceed bench.max_samples".into(),
+            });
+        }
+
+        Ok(config)
+    }
+
+    fn set(&mut self, key: &str, value: Value) -> Result<(), String> {
+        let expected = |expected: &str| {
+            format!(
+                "expected {expected} for \"{key}\", found {}",
+                value.type_name()
+            )
+        };
+        let path = || match &value {
+            Value::String(s) => Ok(PathBuf::from(s)),
+            _ => Err(expected("a string")),
+        };
+        let count = || match value {
+            Value::Integer(n) if n >= 0 => Ok(n.unsigned_abs()),
+            _ => Err(expected("a positive integer")),
+        };
+
+        match key {
+            "year" => {
+                self.year =
+                    Some(u16::try_from(count()?).map_err(|_| expected("a year, e.g. 2023"))?);
+            }
+            "aoc_cli" => match &value {
+                Value::String(s) => self.aoc_cli.clone_from(s),
+                _ => return Err(expected("a string")),
+            },
+            "paths.inputs" => self.paths.inputs = path()?,
+            "paths.examples" => self.paths.examples = path()?,
+            "paths.puzzles" => self.paths.puzzles = path()?,
+            "paths.encrypted_inputs" => self.paths.encrypted_inputs = path()?,
+            "paths.bins" => self.paths.bins = path()?,
+            "paths.solutions" => self.paths.solutions = path()?,
+            "paths.readme" => self.paths.readme = path()?,
+            "paths.progress" => self.paths.progress = path()?,
+            "paths.tags" => self.paths.tags = path()?,
+            "paths.notes" => self.paths.notes = path()?,
+            "paths.solve_log" => self.paths.solve_log = path()?,
+            "bench.budget_ms" => self.bench.budget = Duration::from_millis(count()?),
+            "bench.min_samples" => self.bench.min_samples = count()?.into(),
+            "bench.max_samples" => self.bench.max_samples = count()?.into(),
+            "timeouts.solution_secs" => {
+                self.timeouts.solution = Some(Duration::from_secs(count()?));
+            }
+            "templates.solution" => self.templates.solution = Some(path()?),
+            "encryption.key_file" => self.encryption.key_file = Some(path()?),
+            _ => return Err(format!("unknown setting \"{key}\"")),
+        }
+
+        Ok(())
+    }
+
+    /// Read the config file, falling back to the defaults if it does not exist.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        match fs::read_to_string(path) {
+            Ok(s) => Self::parse(&s),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(Error::IO(path.into(), e)),
+        }
+    }
+
+    /// Apply environment variables and command line overrides, in that order.
+    fn apply_overrides(&mut self, overrides: Overrides) {
+        if let Some(year) = env::var("AOC_YEAR").ok().and_then(|y| y.parse().ok()) {
+            self.year = Some(year);
+        }
+
+        if let Ok(command) = env::var("AOC_CLI") {
+            self.aoc_cli = command;
+        }
+
+        if let Some(year) = overrides.year {
+            self.year = Some(year);
+        }
+    }
+
+    #[must_use]
+    pub fn input_path(&self, day: Day) -> PathBuf {
+        self.paths.inputs.join(format!("{day}.txt"))
+    }
+
+    #[must_use]
+    pub fn puzzle_path(&self, day: Day) -> PathBuf {
+        self.paths.puzzles.join(format!("{day}.md"))
+    }
+
+    #[must_use]
+    pub fn bin_path(&self, day: Day) -> PathBuf {
+        self.paths.bins.join(format!("{day}.rs"))
+    }
+
+    /// Library module of a day's solution, e.g. `src/y2023/day05.rs`. Needs the year to be set.
+    #[must_use]
+    pub fn module_path(&self, day: Day) -> Option<PathBuf> {
+        let year = self.year?;
+        Some(
+            self.paths
+                .solutions
+                .join(format!("y{year}"))
+                .join(format!("day{day}.rs")),
+        )
+    }
+
+    /// Test filter of a day's library module, e.g. `y2023::day05`, derived from `module_path`.
+    #[must_use]
+    pub fn test_filter(&self, day: Day) -> Option<String> {
+        let module = self.module_path(day)?;
+        let module = module.strip_prefix(&self.paths.solutions).ok()?;
+        let components: Vec<_> = module
+            .with_extension("")
+            .iter()
+            .map(|c| c.to_string_lossy().into_owned())
+            .collect();
+        Some(components.join("::"))
+    }
+
+    /// The file of a day's solution: its library module if it has one, otherwise its binary.
+    #[must_use]
+    pub fn solution_path(&self, day: Day) -> PathBuf {
+        self.module_path(day)
+            .filter(|path| path.exists())
+            .unwrap_or_else(|| self.bin_path(day))
+    }
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+fn load_or_exit(overrides: Overrides) -> Config {
+    let path = env::var_os("AOC_CONFIG").map_or_else(|| PathBuf::from(CONFIG_PATH), PathBuf::from);
+
+    match Config::load(&path) {
+        Ok(mut config) => {
+            config.apply_overrides(overrides);
+            config
+        }
+        Err(e) => {
+            eprintln!("Invalid config \"{}\": {e}", path.display());
+            process::exit(1);
+        }
+    }
+}
+
+/// Load the config with command line overrides. Has no effect if the config was loaded before.
+pub fn init(overrides: Overrides) {
+    CONFIG.get_or_init(|| load_or_exit(overrides));
+}
+
+/// The project config, loaded on first use. Exits if the config file is invalid.
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(|| load_or_exit(Overrides::default()))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{path::PathBuf, time::Duration};
+
+    use super::{Config, Error, Overrides};
+    use crate::day;
+
+    #[test]
+    fn parses_config() {
+        let s = r#"
+            year = 2022 # comment
+            aoc_cli = "aoc --session-file .session"
+
+            [paths]
+            inputs = "inputs # not a comment"
+            readme = 'docs\README.md'
+
+            [bench]
+            budget_ms = 250
+            max_samples = 1_000
+
+            [timeouts]
+            solution_secs = 30
+
+            [encryption]
+            key_file = "inputs.key"
+        "#;
+
+        let config = Config::parse(s).unwrap();
+        assert_eq!(config.year, Some(2022));
+        assert_eq!(config.aoc_cli, "aoc --session-file .session");
+        assert_eq!(config.paths.inputs, PathBuf::from("inputs # not a comment"));
+        assert_eq!(config.paths.readme, PathBuf::from(r"docs\README.md"));
+        assert_eq!(config.paths.examples, Config::default().paths.examples);
+        assert_eq!(config.bench.budget, Duration::from_millis(250));
+        assert_eq!(config.bench.min_samples, 10);
+        assert_eq!(config.bench.max_samples, 1000);
+        assert_eq!(config.timeouts.solution, Some(Duration::from_secs(30)));
+        assert_eq!(config.templates.solution, None);
+        assert_eq!(
+            config.encryption.key_file,
+            Some(PathBuf::from("inputs.key"))
+        );
+        assert_eq!(
+            config.input_path(day!(3)),
+            PathBuf::from("inputs # not a comment/03.txt")
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_config() {
+        let line = |s: &str| match Config::parse(s) {
+            Err(Error::Parser { line, .. }) => line,
+            other => panic!("expected parser error, got {other:?}"),
+        };
+
+        assert_eq!(line("year = \"2023\""), 1);
+        assert_eq!(line("\n[paths]\ninput = \"data\""), 3);
+        assert_eq!(line("[bench\n"), 1);
+        assert_eq!(line("[bench]\nmin_samples = 20\nmax_samples = 15"), 3);
+        assert_eq!(
+            line("[bench]\nmax_samples = 5\n\n[paths]\ninputs = \"in\""),
+            2
+        );
+        assert_eq!(line("year"), 1);
+        assert_eq!(line("aoc_cli = 1"), 1);
+    }
+
+    #[test]
+    fn checks_sample_bounds_after_all_keys() {
+        let config = Config::parse("[bench]\nmax_samples = 5\nmin_samples = 1").unwrap();
+        assert_eq!((config.bench.min_samples, config.bench.max_samples), (1, 5));
+    }
+
+    #[test]
+    fn resolves_paths() {
+        let config = Config::parse(
+            "year = 2023\n[paths]\nsolutions = \"solutions\"\ntags = \"meta/tags.txt\"",
+        )
+        .unwrap();
+        assert_eq!(
+            config.module_path(day!(5)),
+            Some(PathBuf::from("solutions/y2023/day05.rs"))
+        );
+        assert_eq!(config.test_filter(day!(5)), Some("y2023::day05".into()));
+        assert_eq!(config.paths.tags, PathBuf::from("meta/tags.txt"));
+        assert_eq!(config.paths.progress, PathBuf::from("data/progress.txt"));
+        assert_eq!(Config::default().module_path(day!(5)), None);
+        assert_eq!(Config::default().test_filter(day!(5)), None);
+    }
+
+    #[test]
+    fn applies_overrides() {
+        let mut config = Config::parse("year = 2020").unwrap();
+        config.apply_overrides(Overrides { year: Some(2021) });
+        assert_eq!(config.year, Some(2021));
+    }
+}
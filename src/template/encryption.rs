@@ -0,0 +1,223 @@
+/// Encrypted copies of the puzzle inputs, which can be committed while the plaintext inputs stay private.
+///
+/// Files are encrypted with ChaCha20-Poly1305, the key is derived with Argon2id from a passphrase or a key file
+/// and a random salt per file. The day is authenticated as well, so files can't be swapped undetected.
+/// A file consists of the magic bytes, the salt, the nonce and the ciphertext.
+use std::{
+    env,
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use argon2::Argon2;
+use chacha20poly1305::{
+    aead::{rand_core::RngCore, Aead, AeadCore, KeyInit, OsRng, Payload},
+    ChaCha20Poly1305, Key, Nonce,
+};
+
+use crate::template::config;
+use crate::Day;
+
+const MAGIC: &[u8] = b"AOCENC01";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+#[derive(Debug)]
+pub enum Error {
+    IO(io::Error),
+    /// Neither a key file nor a passphrase is configured.
+    NoSecret,
+    /// The file is not an encrypted input.
+    Format,
+    /// Wrong passphrase or key file, or the file was modified.
+    Decryption,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::IO(e) => write!(f, "{e}"),
+            Error::NoSecret => write!(
+                f,
+                "no key, set AOC_INPUTS_PASSPHRASE or AOC_INPUTS_KEY_FILE, or `key_file` in the `[encryption]` section of aoc.toml."
+            ),
+            Error::Format => write!(f, "not an encrypted input file."),
+            Error::Decryption => write!(
+                f,
+                "could not decrypt, the key is wrong or the file was modified."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+/// Secret the encryption key is derived from.
+pub enum Secret {
+    Passphrase(String),
+    KeyFile(Vec<u8>),
+}
+
+impl Secret {
+    pub fn from_key_file(path: &Path) -> Result<Self, io::Error> {
+        fs::read(path).map(Secret::KeyFile)
+    }
+
+    /// The secret from the `AOC_INPUTS_KEY_FILE` or `AOC_INPUTS_PASSPHRASE` environment variables,
+    /// or the key file configured in `aoc.toml`, in that order.
+    pub fn from_env() -> Result<Option<Self>, io::Error> {
+        if let Some(path) = env::var_os("AOC_INPUTS_KEY_FILE") {
+            return Self::from_key_file(Path::new(&path)).map(Some);
+        }
+
+        if let Ok(passphrase) = env::var("AOC_INPUTS_PASSPHRASE") {
+            return Ok(Some(Secret::Passphrase(passphrase)));
+        }
+
+        match &config::get().encryption.key_file {
+            Some(path) => Self::from_key_file(path).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        match self {
+            Secret::Passphrase(passphrase) => passphrase.as_bytes(),
+            Secret::KeyFile(key) => key,
+        }
+    }
+
+    fn derive_key(&self, salt: &[u8]) -> Key {
+        let mut key = Key::default();
+        Argon2::default()
+            .hash_password_into(self.as_bytes(), salt, &mut key)
+            .expect("argon2 accepts any secret and a 16 byte salt");
+        key
+    }
+}
+
+/// Path of the encrypted copy of a day's input.
+#[must_use]
+pub fn encrypted_path(day: Day) -> PathBuf {
+    config::get()
+        .paths
+        .encrypted_inputs
+        .join(format!("{day}.txt.enc"))
+}
+
+#[must_use]
+pub fn encrypt(plaintext: &[u8], secret: &Secret, day: Day) -> Vec<u8> {
+    let mut salt = [0; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let aad = day.to_string();
+
+    let ciphertext = ChaCha20Poly1305::new(&secret.derive_key(&salt))
+        .encrypt(
+            &nonce,
+            Payload {
+                msg: plaintext,
+                aad: aad.as_bytes(),
+            },
+        )
+        .expect("inputs are far below the size limit of the cipher");
+
+    [MAGIC, &salt, &nonce, &ciphertext].concat()
+}
+
+pub fn decrypt(data: &[u8], secret: &Secret, day: Day) -> Result<Vec<u8>, Error> {
+    let data = data.strip_prefix(MAGIC).ok_or(Error::Format)?;
+    if data.len() < SALT_LEN + NONCE_LEN {
+        return Err(Error::Format);
+    }
+
+    let (salt, data) = data.split_at(SALT_LEN);
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+    let aad = day.to_string();
+
+    ChaCha20Poly1305::new(&secret.derive_key(salt))
+        .decrypt(
+            Nonce::from_slice(nonce),
+            Payload {
+                msg: ciphertext,
+                aad: aad.as_bytes(),
+            },
+        )
+        .map_err(|_| Error::Decryption)
+}
+
+/// Decrypt the encrypted copy of a missing input file into memory.
+/// Returns `None` if `path` is not an input or there is no encrypted copy.
+#[must_use]
+pub fn read_encrypted_copy(path: &Path) -> Option<Result<String, Error>> {
+    if !path.parent()?.ends_with(&config::get().paths.inputs) {
+        return None;
+    }
+
+    let day: Day = path
+        .file_name()?
+        .to_str()?
+        .strip_suffix(".txt")?
+        .parse()
+        .ok()?;
+    let data = fs::read(encrypted_path(day)).ok()?;
+
+    Some(decrypt_to_string(&data, day))
+}
+
+fn decrypt_to_string(data: &[u8], day: Day) -> Result<String, Error> {
+    let secret = Secret::from_env()?.ok_or(Error::NoSecret)?;
+    let plaintext = decrypt(data, &secret, day)?;
+    String::from_utf8(plaintext).map_err(|_| Error::Format)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{decrypt, encrypt, Error, Secret, MAGIC};
+    use crate::day;
+
+    #[test]
+    fn roundtrips_inputs() {
+        let secret = Secret::Passphrase("correct horse".into());
+        let encrypted = encrypt(b"1abc2\n", &secret, day!(1));
+
+        assert!(encrypted.starts_with(MAGIC));
+        assert_eq!(decrypt(&encrypted, &secret, day!(1)).unwrap(), b"1abc2\n");
+        // salt and nonce are random.
+        assert_ne!(encrypted, encrypt(b"1abc2\n", &secret, day!(1)));
+    }
+
+    #[test]
+    fn rejects_tampering() {
+        let secret = Secret::KeyFile(vec![7; 32]);
+        let mut encrypted = encrypt(b"1abc2\n", &secret, day!(1));
+
+        let wrong = Secret::Passphrase("guess".into());
+        assert!(matches!(
+            decrypt(&encrypted, &wrong, day!(1)),
+            Err(Error::Decryption)
+        ));
+        assert!(matches!(
+            decrypt(&encrypted, &secret, day!(2)),
+            Err(Error::Decryption)
+        ));
+        assert!(matches!(
+            decrypt(b"1abc2\n", &secret, day!(1)),
+            Err(Error::Format)
+        ));
+
+        let last = encrypted.len() - 1;
+        encrypted[last] ^= 1;
+        assert!(matches!(
+            decrypt(&encrypted, &secret, day!(1)),
+            Err(Error::Decryption)
+        ));
+    }
+}
@@ -0,0 +1,279 @@
+/// Sanity checks and normalisation applied to puzzle inputs and examples before they are handed to a solution.
+/// `scaffold` creates empty data files, and parsers tend to `unwrap` their way into an unhelpful panic on them.
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::template::encryption;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum InputIssue {
+    Missing,
+    /// The file is missing and its encrypted copy could not be decrypted.
+    Encrypted(String),
+    Unreadable(io::ErrorKind),
+    Empty,
+    ChecksumMismatch {
+        expected: u64,
+        actual: u64,
+    },
+    MissingTrailingNewline,
+    CrlfLineEndings,
+}
+
+impl InputIssue {
+    /// Fatal issues prevent the solution from running, the others are reported as warnings.
+    #[must_use]
+    pub fn is_fatal(&self) -> bool {
+        !matches!(
+            self,
+            InputIssue::MissingTrailingNewline | InputIssue::CrlfLineEndings
+        )
+    }
+}
+
+impl Display for InputIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputIssue::Missing => write!(f, "file does not exist."),
+            InputIssue::Encrypted(e) => write!(f, "file does not exist, its encrypted copy: {e}"),
+            InputIssue::Unreadable(kind) => write!(f, "file could not be read ({kind})."),
+            InputIssue::Empty => write!(f, "file is empty, it probably is a scaffolded placeholder."),
+            InputIssue::ChecksumMismatch { expected, actual } => write!(
+                f,
+                "checksum {actual:016x} does not match the one recorded on download ({expected:016x})."
+            ),
+            InputIssue::MissingTrailingNewline => write!(f, "file does not end with a newline."),
+            InputIssue::CrlfLineEndings => write!(f, "file uses CRLF line endings."),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrailingNewline {
+    /// Leave the end of the file untouched.
+    Keep,
+    /// End the file with exactly one newline.
+    Exactly,
+    /// Remove all trailing newlines.
+    Strip,
+}
+
+/// Normalisation policy applied by `read_file` and the runner.
+/// Solutions can pick their own policy with `solution!(day, policy)`, e.g. a `const` built from
+/// `Normalization::DEFAULT.with_trailing_newline(TrailingNewline::Keep)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Normalization {
+    pub strip_bom: bool,
+    pub crlf_to_lf: bool,
+    pub trailing_newline: TrailingNewline,
+}
+
+impl Normalization {
+    /// Strip the BOM, convert CRLF to LF and end the file with exactly one newline.
+    pub const DEFAULT: Normalization = Normalization {
+        strip_bom: true,
+        crlf_to_lf: true,
+        trailing_newline: TrailingNewline::Exactly,
+    };
+
+    /// Hand the file contents to the solution as they are.
+    pub const RAW: Normalization = Normalization {
+        strip_bom: false,
+        crlf_to_lf: false,
+        trailing_newline: TrailingNewline::Keep,
+    };
+
+    #[must_use]
+    pub const fn with_strip_bom(mut self, strip_bom: bool) -> Self {
+        self.strip_bom = strip_bom;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_crlf_to_lf(mut self, crlf_to_lf: bool) -> Self {
+        self.crlf_to_lf = crlf_to_lf;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_trailing_newline(mut self, trailing_newline: TrailingNewline) -> Self {
+        self.trailing_newline = trailing_newline;
+        self
+    }
+
+    #[must_use]
+    pub fn apply(&self, mut contents: String) -> String {
+        if self.strip_bom && contents.starts_with('\u{feff}') {
+            contents.drain(..'\u{feff}'.len_utf8());
+        }
+
+        if self.crlf_to_lf && contents.contains("\r\n") {
+            contents = contents.replace("\r\n", "\n");
+        }
+
+        match self.trailing_newline {
+            TrailingNewline::Keep => {}
+            TrailingNewline::Exactly => {
+                contents.truncate(contents.trim_end_matches('\n').len());
+                contents.push('\n');
+            }
+            TrailingNewline::Strip => {
+                contents.truncate(contents.trim_end_matches('\n').len());
+            }
+        }
+
+        contents
+    }
+}
+
+impl Default for Normalization {
+    fn default() -> Self {
+        Normalization::DEFAULT
+    }
+}
+
+/// Contents of a data file that passed all fatal checks.
+pub struct CheckedInput {
+    pub contents: String,
+    pub warnings: Vec<InputIssue>,
+}
+
+/// Reads a data file, checks it for issues and normalises it.
+/// The checksum is computed on the raw file and only verified if one was recorded next to the file, see [`record_checksum`].
+/// Warnings are reported for quirks that remain after normalisation.
+pub fn load(path: &Path, normalization: Normalization) -> Result<CheckedInput, InputIssue> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        // fall back to the encrypted copy of an input that is not checked out.
+        Err(e) if e.kind() == io::ErrorKind::NotFound => encryption::read_encrypted_copy(path)
+            .ok_or(InputIssue::Missing)?
+            .map_err(|e| InputIssue::Encrypted(e.to_string()))?,
+        Err(e) => return Err(InputIssue::Unreadable(e.kind())),
+    };
+
+    if let Some(expected) = read_checksum(path) {
+        let actual = checksum(&contents);
+        if actual != expected {
+            return Err(InputIssue::ChecksumMismatch { expected, actual });
+        }
+    }
+
+    let contents = normalization.apply(contents);
+    let mut warnings = check_contents(&contents)?;
+
+    // a missing trailing newline was asked for explicitly.
+    if normalization.trailing_newline == TrailingNewline::Strip {
+        warnings.retain(|w| *w != InputIssue::MissingTrailingNewline);
+    }
+
+    Ok(CheckedInput { contents, warnings })
+}
+
+fn check_contents(contents: &str) -> Result<Vec<InputIssue>, InputIssue> {
+    if contents.trim().is_empty() {
+        return Err(InputIssue::Empty);
+    }
+
+    let mut warnings = vec![];
+
+    if contents.contains("\r\n") {
+        warnings.push(InputIssue::CrlfLineEndings);
+    }
+
+    if !contents.ends_with('\n') {
+        warnings.push(InputIssue::MissingTrailingNewline);
+    }
+
+    Ok(warnings)
+}
+
+/// 64 bit FNV-1a hash. Unlike `DefaultHasher`, the output is stable across Rust releases.
+#[must_use]
+pub fn checksum(contents: &str) -> u64 {
+    contents.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+fn get_checksum_path(path: &Path) -> PathBuf {
+    path.with_extension("checksum")
+}
+
+fn read_checksum(path: &Path) -> Option<u64> {
+    let recorded = fs::read_to_string(get_checksum_path(path)).ok()?;
+    u64::from_str_radix(recorded.trim(), 16).ok()
+}
+
+/// Records the checksum of a freshly downloaded file, so that later edits or truncations are detected.
+pub fn record_checksum(path: &Path) -> Result<(), io::Error> {
+    let contents = fs::read_to_string(path)?;
+    fs::write(
+        get_checksum_path(path),
+        format!("{:016x}\n", checksum(&contents)),
+    )
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check_contents, checksum, InputIssue, Normalization, TrailingNewline};
+
+    #[test]
+    fn rejects_empty_input() {
+        assert_eq!(check_contents("").err(), Some(InputIssue::Empty));
+        assert_eq!(check_contents(" \n\n").err(), Some(InputIssue::Empty));
+    }
+
+    #[test]
+    fn accepts_well_formed_input() {
+        assert_eq!(check_contents("1\n2\n").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn warns_about_line_endings() {
+        assert_eq!(
+            check_contents("1\r\n2").unwrap(),
+            vec![
+                InputIssue::CrlfLineEndings,
+                InputIssue::MissingTrailingNewline
+            ]
+        );
+        assert!(!InputIssue::CrlfLineEndings.is_fatal());
+        assert!(InputIssue::Empty.is_fatal());
+    }
+
+    #[test]
+    fn default_normalization() {
+        let n = Normalization::DEFAULT;
+        assert_eq!(n.apply("\u{feff}1\r\n2".into()), "1\n2\n");
+        assert_eq!(n.apply("1\n2\n\n\n".into()), "1\n2\n");
+        assert_eq!(n.apply("1\n2\n".into()), "1\n2\n");
+    }
+
+    #[test]
+    fn configured_normalization() {
+        let strip = Normalization::DEFAULT.with_trailing_newline(TrailingNewline::Strip);
+        assert_eq!(strip.apply("1\r\n2\r\n".into()), "1\n2");
+
+        let keep_crlf = Normalization::DEFAULT.with_crlf_to_lf(false);
+        assert_eq!(keep_crlf.apply("1\r\n2".into()), "1\r\n2\n");
+
+        let raw = "\u{feff}1\r\n\n\n";
+        assert_eq!(Normalization::RAW.apply(raw.into()), raw);
+    }
+
+    #[test]
+    fn const_normalization() {
+        const RAW_END: Normalization =
+            Normalization::DEFAULT.with_trailing_newline(TrailingNewline::Keep);
+        assert_eq!(RAW_END.apply("\u{feff}a\r\nb\n\n".into()), "a\nb\n\n");
+    }
+
+    #[test]
+    fn checksum_is_stable() {
+        assert_eq!(checksum(""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(checksum("a"), 0xaf63_dc4c_8601_ec8c);
+    }
+}
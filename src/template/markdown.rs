@@ -0,0 +1,398 @@
+/// Renders the puzzle descriptions saved by aoc-cli to text with ANSI styles.
+/// Supports the markdown found in these files: headings, emphasis, inline code, code blocks, lists and links.
+use crate::template::{strip_ansi, ANSI_BOLD, ANSI_DIM, ANSI_RESET};
+
+const PART_TWO_HEADING: &str = "--- Part Two ---";
+/// Shown below a part once it is solved, followed by the accepted answer.
+const ANSWER_PREFIX: &str = "Your puzzle answer was";
+
+fn is_rule(line: &str) -> bool {
+    let line = line.trim();
+    line.len() >= 3 && (line.chars().all(|c| c == '-') || line.chars().all(|c| c == '='))
+}
+
+fn is_fence(line: &str) -> bool {
+    line.trim_start().starts_with("```")
+}
+
+/// Returns the list marker and the item text if the line starts a list item.
+fn list_item(line: &str) -> Option<(String, &str)> {
+    let trimmed = line.trim_start();
+
+    for bullet in ["* ", "- ", "+ "] {
+        if let Some(text) = trimmed.strip_prefix(bullet) {
+            return Some(("•".into(), text));
+        }
+    }
+
+    let (number, text) = trimmed.split_once(". ")?;
+    if !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()) {
+        return Some((format!("{number}."), text));
+    }
+
+    None
+}
+
+/// Removes the backslash escapes of markdown, e.g. `\---` becomes `---`.
+fn unescape(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            if let Some(next) = chars.next() {
+                result.push(next);
+            }
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+/// Renders inline markup. Emphasis, which AoC uses to highlight important numbers, is shown in bold.
+fn render_inline(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut result = String::with_capacity(text.len());
+    let mut emphasis = 0;
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '\\' if i + 1 < chars.len() => {
+                result.push(chars[i + 1]);
+                i += 2;
+            }
+            '`' => {
+                let Some(len) = chars[i + 1..].iter().position(|&c| c == '`') else {
+                    result.push('`');
+                    i += 1;
+                    continue;
+                };
+                let code: String = chars[i + 1..i + 1 + len].iter().collect();
+                // highlighted code is written as `*code*`.
+                match code.strip_prefix('*').and_then(|c| c.strip_suffix('*')) {
+                    Some(highlighted) if !highlighted.is_empty() && emphasis == 0 => {
+                        result.push_str(&format!("{ANSI_BOLD}{highlighted}{ANSI_RESET}"));
+                    }
+                    _ => result.push_str(&code),
+                }
+                i += len + 2;
+            }
+            '*' => {
+                // both `*em*` and `**strong**` are rendered in bold.
+                let len = chars[i..].iter().take_while(|&&c| c == '*').count();
+                let opens = chars.get(i + len).is_some_and(|c| !c.is_whitespace());
+                let closes = emphasis > 0 && i > 0 && !chars[i - 1].is_whitespace();
+
+                if closes {
+                    emphasis -= 1;
+                    if emphasis == 0 {
+                        result.push_str(ANSI_RESET);
+                    }
+                } else if opens {
+                    if emphasis == 0 {
+                        result.push_str(ANSI_BOLD);
+                    }
+                    emphasis += 1;
+                } else {
+                    result.extend(&chars[i..i + len]);
+                }
+                i += len;
+            }
+            '[' => {
+                // `[text](url)` is shown as its text.
+                let rest: String = chars[i + 1..].iter().collect();
+                let link = rest.split_once("](").and_then(|(label, after)| {
+                    let url = &after[..after.find(')')?];
+                    Some((
+                        label.to_string(),
+                        label.chars().count() + url.chars().count() + 3,
+                    ))
+                });
+
+                match link {
+                    Some((label, len)) if !label.contains('[') => {
+                        result.push_str(&render_inline(&label));
+                        if emphasis > 0 {
+                            result.push_str(ANSI_BOLD);
+                        }
+                        i += len + 1;
+                    }
+                    _ => {
+                        result.push('[');
+                        i += 1;
+                    }
+                }
+            }
+            c => {
+                result.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    if emphasis > 0 {
+        result.push_str(ANSI_RESET);
+    }
+
+    result
+}
+
+/// Wraps styled text at word boundaries, ignoring ANSI codes when measuring.
+fn wrap(text: &str, width: usize, first_prefix: &str, prefix: &str) -> Vec<String> {
+    let mut lines = vec![];
+    let mut line = first_prefix.to_string();
+    let mut line_width = first_prefix.chars().count();
+    let mut is_empty = true;
+
+    for word in text.split_whitespace() {
+        let word_width = strip_ansi(word).chars().count();
+
+        if !is_empty && line_width + 1 + word_width > width {
+            lines.push(line);
+            line = prefix.to_string();
+            line_width = prefix.chars().count();
+            is_empty = true;
+        }
+
+        if !is_empty {
+            line.push(' ');
+            line_width += 1;
+        }
+
+        line.push_str(word);
+        line_width += word_width;
+        is_empty = false;
+    }
+
+    lines.push(line);
+    lines
+}
+
+enum Block {
+    Paragraph(Vec<String>),
+    Item(String, Vec<String>),
+}
+
+impl Block {
+    fn render(self, width: usize, out: &mut Vec<String>) {
+        match self {
+            Block::Paragraph(lines) => {
+                out.extend(wrap(&render_inline(&lines.join(" ")), width, "", ""));
+            }
+            Block::Item(marker, lines) => {
+                let first = format!("  {marker} ");
+                let rest = " ".repeat(first.chars().count());
+                out.extend(wrap(&render_inline(&lines.join(" ")), width, &first, &rest));
+            }
+        }
+    }
+}
+
+/// Renders markdown to lines of styled text, wrapped to the given width.
+#[must_use]
+pub fn render(markdown: &str, width: usize) -> String {
+    let mut out: Vec<String> = vec![];
+    let mut block: Option<Block> = None;
+    let mut in_code = false;
+
+    let flush = |block: &mut Option<Block>, out: &mut Vec<String>| {
+        if let Some(block) = block.take() {
+            block.render(width, out);
+        }
+    };
+    let separate = |out: &mut Vec<String>| {
+        if out.last().is_some_and(|l| !l.is_empty()) {
+            out.push(String::new());
+        }
+    };
+
+    for line in markdown.lines() {
+        if is_fence(line) {
+            flush(&mut block, &mut out);
+            if !in_code {
+                separate(&mut out);
+            }
+            in_code = !in_code;
+            continue;
+        }
+
+        if in_code {
+            out.push(format!("    {ANSI_DIM}{line}{ANSI_RESET}"));
+            continue;
+        }
+
+        if line.trim().is_empty() {
+            flush(&mut block, &mut out);
+            separate(&mut out);
+            continue;
+        }
+
+        if let Some(heading) = line.strip_prefix('#') {
+            flush(&mut block, &mut out);
+            separate(&mut out);
+            let heading = unescape(heading.trim_start_matches('#').trim());
+            out.push(format!("{ANSI_BOLD}{heading}{ANSI_RESET}"));
+            out.push(String::new());
+            continue;
+        }
+
+        if is_rule(line) {
+            match block.take() {
+                // a line underlined with `---` or `===` is a heading.
+                Some(Block::Paragraph(lines)) => {
+                    let heading = unescape(&lines.join(" "));
+                    out.push(format!("{ANSI_BOLD}{heading}{ANSI_RESET}"));
+                    out.push(String::new());
+                }
+                mut other => {
+                    flush(&mut other, &mut out);
+                    separate(&mut out);
+                    out.push("─".repeat(width));
+                }
+            }
+            continue;
+        }
+
+        if let Some((marker, text)) = list_item(line) {
+            flush(&mut block, &mut out);
+            block = Some(Block::Item(marker, vec![text.to_string()]));
+            continue;
+        }
+
+        match &mut block {
+            Some(Block::Paragraph(lines) | Block::Item(_, lines)) => {
+                lines.push(line.trim().to_string());
+            }
+            None if line.starts_with("    ") || line.starts_with('\t') => {
+                out.push(format!("    {ANSI_DIM}{}{ANSI_RESET}", line.trim_start()));
+            }
+            None => block = Some(Block::Paragraph(vec![line.trim().to_string()])),
+        }
+    }
+
+    flush(&mut block, &mut out);
+
+    while out.last().is_some_and(String::is_empty) {
+        out.pop();
+    }
+
+    out.join("\n")
+}
+
+/// Returns the section of a puzzle description for one part.
+/// Part two is only present after part one was solved and the puzzle was downloaded again.
+#[must_use]
+pub fn select_part(markdown: &str, part: u8) -> Option<&str> {
+    let split = markdown
+        .match_indices('\n')
+        .map(|(i, _)| i + 1)
+        .find(|&i| unescape(markdown[i..].lines().next().unwrap_or("")).contains(PART_TWO_HEADING));
+
+    match (part, split) {
+        (1, Some(i)) => Some(&markdown[..i]),
+        (1, None) => Some(markdown),
+        (2, Some(i)) => Some(&markdown[i..]),
+        _ => None,
+    }
+}
+
+/// Returns the accepted answer of a part, shown in the puzzle description once the part is solved.
+#[must_use]
+pub fn accepted_answer(markdown: &str, part: u8) -> Option<String> {
+    let section = select_part(markdown, part)?;
+    let (_, rest) = section.split_once(ANSWER_PREFIX)?;
+    let answer = rest
+        .lines()
+        .next()?
+        .trim()
+        .trim_end_matches('.')
+        .trim_matches('`');
+    (!answer.is_empty()).then(|| unescape(answer))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{accepted_answer, render, select_part};
+    use crate::template::{strip_ansi, ANSI_BOLD, ANSI_RESET};
+
+    const PUZZLE: &str = r"\--- Day 1: Trebuchet?! ---
+----------
+
+Something is wrong with global snow production, and you've been selected to take a look. The Elves have even given you a map; on it, they've used stars to mark the top fifty locations.
+
+For example:
+
+```
+1abc2
+pqr3stu8vwx
+```
+
+In this example, the calibration values are:
+
+* `12` for the first line
+* `38` for the
+  second line
+
+Adding these together produces `*142*`. See [the docs](https://example.com/docs) for more.
+
+\--- Part Two ---
+----------
+
+Your calculation isn't quite right. What is the *sum of all of the calibration values*?
+";
+
+    #[test]
+    fn renders_markdown() {
+        let rendered = render(PUZZLE, 60);
+        let lines: Vec<String> = rendered.lines().map(strip_ansi).collect();
+
+        assert!(rendered.starts_with(&format!(
+            "{ANSI_BOLD}--- Day 1: Trebuchet?! ---{ANSI_RESET}\n\n"
+        )));
+        assert!(lines.iter().all(|l| l.chars().count() <= 60));
+        assert_eq!(
+            lines[2..4],
+            [
+                "Something is wrong with global snow production, and you've",
+                "been selected to take a look. The Elves have even given you"
+            ]
+        );
+        assert!(lines.contains(&"    1abc2".to_string()));
+        assert!(lines.contains(&"  • 12 for the first line".to_string()));
+        assert!(lines.contains(&"  • 38 for the second line".to_string()));
+        assert!(lines
+            .contains(&"Adding these together produces 142. See the docs for more.".to_string()));
+        assert!(rendered.replace('\n', " ").contains(&format!(
+            "{ANSI_BOLD}sum of all of the calibration values{ANSI_RESET}?"
+        )));
+    }
+
+    #[test]
+    fn selects_parts() {
+        let part_one = select_part(PUZZLE, 1).unwrap();
+        assert!(part_one.contains("Day 1"));
+        assert!(!part_one.contains("Part Two"));
+
+        let part_two = select_part(PUZZLE, 2).unwrap();
+        assert!(part_two.starts_with(r"\--- Part Two ---"));
+
+        let locked = select_part(part_one, 2);
+        assert_eq!(locked, None);
+        assert_eq!(select_part(part_one, 1), Some(part_one));
+    }
+
+    #[test]
+    fn finds_accepted_answers() {
+        assert_eq!(accepted_answer(PUZZLE, 1), None);
+
+        let solved = PUZZLE.replace(
+            "for more.\n",
+            "for more.\n\nYour puzzle answer was `54597`.\n",
+        ) + "\nYour puzzle answer was `54504`.\n";
+        assert_eq!(accepted_answer(&solved, 1), Some("54597".into()));
+        assert_eq!(accepted_answer(&solved, 2), Some("54504".into()));
+    }
+}
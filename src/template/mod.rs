@@ -1,41 +1,131 @@
 use crate::Day;
-use std::{env, fs};
+use std::{env, path::PathBuf};
 
 pub mod aoc_cli;
+pub mod bench_report;
+pub mod cli;
 pub mod commands;
+pub mod config;
+pub mod encryption;
+pub mod input;
+pub mod markdown;
+pub mod notes;
+pub mod progress;
 pub mod readme_benchmarks;
+pub mod readme_chart;
+pub mod readme_solve_times;
+pub mod readme_stars;
 pub mod runner;
+pub mod search;
+pub mod solve_log;
+pub mod tags;
+
+pub use input::{Normalization, TrailingNewline};
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
+pub const ANSI_DIM: &str = "\x1b[2m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+/// Removes the ANSI codes above from output of the runner.
 #[must_use]
-pub fn read_file(folder: &str, day: Day, part: i32) -> String {
+pub fn strip_ansi(s: &str) -> String {
+    [ANSI_BOLD, ANSI_ITALIC, ANSI_DIM, ANSI_RESET]
+        .iter()
+        .fold(s.to_string(), |s, code| s.replace(code, ""))
+}
+
+/// Returns the path of a data file, e.g. `data/examples/01-2.txt`.
+/// The `inputs`, `examples` and `puzzles` folders can be moved in the [`config`].
+#[must_use]
+pub fn get_data_path(folder: &str, day: Day, part: i32) -> PathBuf {
     let cwd = env::current_dir().unwrap();
-    let mut filepath = cwd.join("data").join(folder);
+    let paths = &config::get().paths;
+    let filepath = match folder {
+        "inputs" => cwd.join(&paths.inputs),
+        "examples" => cwd.join(&paths.examples),
+        "puzzles" => cwd.join(&paths.puzzles),
+        _ => cwd.join("data").join(folder),
+    };
     if part == 0 {
-        filepath = filepath.join(format!("{day}.txt"));
+        filepath.join(format!("{day}.txt"))
     } else {
-        filepath = filepath.join(format!("{day}-{part}.txt"));
+        filepath.join(format!("{day}-{part}.txt"))
+    }
+}
+
+/// Helper function that reads a text file to a string, normalised with [`Normalization::DEFAULT`].
+/// Panics with a descriptive message if the file is missing, empty or was modified after download.
+#[must_use]
+pub fn read_file(folder: &str, day: Day, part: i32) -> String {
+    read_file_with(folder, day, part, Normalization::DEFAULT)
+}
+
+/// Same as [`read_file`], with a custom normalisation policy.
+#[must_use]
+pub fn read_file_with(folder: &str, day: Day, part: i32, normalization: Normalization) -> String {
+    let filepath = get_data_path(folder, day, part);
+    match input::load(&filepath, normalization) {
+        Ok(checked) => {
+            for warning in checked.warnings {
+                eprintln!("Warning: \"{}\": {warning}", filepath.display());
+            }
+            checked.contents
+        }
+        Err(issue) => panic!("could not use \"{}\": {issue}", filepath.display()),
     }
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `DAY` and `NORMALIZATION` and sets up the input and runner for each part.
+/// The input normalisation policy defaults to [`Normalization::DEFAULT`] and can be passed as second argument.
+///
+/// Alternative implementations of a part can be registered as variants, they are compared with `solve --variants`:
+/// `solution!(18, variants = { part_one: [part_one, part_one_shoelace], part_two: [part_two] })`.
+///
+/// A parse step shared by the parts can be registered last, e.g. `solution!(22, parse = parse)`.
+/// It is timed on its own and shown in the `Parse` column of the benchmark table.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
+    (@impl $day:expr, $normalization:expr, [$($parse:ident)?], [$($one:ident),+], [$($two:ident),+]) => {
         /// The current day.
         const DAY: advent_of_code::Day = advent_of_code::day!($day);
 
+        /// How input files of the current day are normalised, pass it to `read_file_with` in tests.
+        #[allow(dead_code)]
+        const NORMALIZATION: advent_of_code::template::Normalization = $normalization;
+
         fn main() {
             use advent_of_code::template::runner::*;
-            let input = advent_of_code::template::read_file("inputs", DAY, 0);
-            run_part(part_one, &input, DAY, 1);
-            run_part(part_two, &input, DAY, 2);
+            // reject invalid arguments before reading the input.
+            let args = advent_of_code::template::cli::SolutionArgs::get();
+            let input = read_input(DAY, NORMALIZATION);
+            $(run_parse($parse, &input);)?
+            let mut agree = true;
+            if args.variants {
+                agree &= run_variants(&[$(Variant { name: stringify!($one), func: &$one }),+], &input, 1);
+                agree &= run_variants(&[$(Variant { name: stringify!($two), func: &$two }),+], &input, 2);
+            } else {
+                run_part(part_one, &input, DAY, 1);
+                run_part(part_two, &input, DAY, 2);
+            }
+            print_peak_memory();
+            // fail `solve --variants` when variants disagree, so scripts and CI notice.
+            if !agree {
+                std::process::exit(1);
+            }
         }
     };
+    ($day:expr, variants = { part_one: [$($one:ident),+ $(,)?], part_two: [$($two:ident),+ $(,)?] $(,)? } $(, parse = $parse:ident)? $(,)?) => {
+        advent_of_code::solution!(@impl $day, advent_of_code::template::Normalization::DEFAULT, [$($parse)?], [$($one),+], [$($two),+]);
+    };
+    ($day:expr, $normalization:expr, variants = { part_one: [$($one:ident),+ $(,)?], part_two: [$($two:ident),+ $(,)?] $(,)? } $(, parse = $parse:ident)? $(,)?) => {
+        advent_of_code::solution!(@impl $day, $normalization, [$($parse)?], [$($one),+], [$($two),+]);
+    };
+    // before the normalisation arm, which would take `parse = parse` as an assignment expression.
+    ($day:expr $(, parse = $parse:ident)?) => {
+        advent_of_code::solution!(@impl $day, advent_of_code::template::Normalization::DEFAULT, [$($parse)?], [part_one], [part_two]);
+    };
+    ($day:expr, $normalization:expr $(, parse = $parse:ident)?) => {
+        advent_of_code::solution!(@impl $day, $normalization, [$($parse)?], [part_one], [part_two]);
+    };
 }
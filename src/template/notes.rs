@@ -0,0 +1,55 @@
+/// Short notes per day, e.g. the approach taken, shown in the `Notes` column of the benchmark table.
+/// Notes are maintained by hand in `data/notes.txt`, see `paths.notes` in the config.
+use std::{collections::BTreeMap, fs, io};
+
+use crate::template::config;
+use crate::Day;
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Notes {
+    days: BTreeMap<Day, String>,
+}
+
+impl Notes {
+    pub fn load() -> Result<Self, io::Error> {
+        match fs::read_to_string(&config::get().paths.notes) {
+            Ok(s) => Ok(Self::parse(&s)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Each line holds `<day> <note>`. Lines starting with `#` are comments, a note may contain `#`.
+    pub(crate) fn parse(s: &str) -> Self {
+        let days = s
+            .lines()
+            .filter(|line| !line.trim_start().starts_with('#'))
+            .filter_map(|line| {
+                let (day, note) = line.trim().split_once(char::is_whitespace)?;
+                Some((day.parse().ok()?, note.trim().to_string()))
+            })
+            .collect();
+
+        Self { days }
+    }
+
+    #[must_use]
+    pub fn get(&self, day: Day) -> Option<&str> {
+        self.days.get(&day).map(String::as_str)
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Notes;
+    use crate::day;
+
+    #[test]
+    fn parses_notes() {
+        let notes = Notes::parse("# day note\n05 ranges split at #boundaries\n07\nnot a day\n");
+
+        assert_eq!(notes.get(day!(5)), Some("ranges split at #boundaries"));
+        assert_eq!(notes.get(day!(7)), None);
+        assert_eq!(notes.get(day!(1)), None);
+    }
+}
@@ -0,0 +1,171 @@
+/// Local store of solved puzzle parts, kept up to date by `--submit` verdicts and by the accepted answers
+/// that solved puzzle descriptions show, which are picked up when a solution runs.
+/// Allows rendering progress without network access, see the `progress` command.
+use std::{collections::BTreeMap, fs, io};
+
+use crate::template::{aoc_cli::SubmitVerdict, config};
+use crate::Day;
+
+/// Solved parts, mapped to the accepted answer if it is known.
+/// The answer is unknown if the part was solved outside of this repository.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Progress {
+    solved: BTreeMap<(Day, u8), Option<String>>,
+}
+
+/// Outcome of comparing an answer to the accepted one.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verification {
+    Unknown,
+    Matches,
+    Differs(String),
+}
+
+impl Progress {
+    pub fn load() -> Result<Self, io::Error> {
+        match fs::read_to_string(&config::get().paths.progress) {
+            Ok(s) => Ok(Self::parse(&s)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self) -> Result<(), io::Error> {
+        fs::write(&config::get().paths.progress, self.to_string())
+    }
+
+    /// Each line holds `<day> <part> [answer]`, lines that can't be parsed are skipped.
+    fn parse(s: &str) -> Self {
+        let solved = s
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.trim().splitn(3, ' ');
+                let day = fields.next()?.parse().ok()?;
+                let part = fields.next()?.parse().ok().filter(|p| *p == 1 || *p == 2)?;
+                let answer = fields.next().map(str::to_string);
+                Some(((day, part), answer))
+            })
+            .collect();
+
+        Self { solved }
+    }
+
+    #[must_use]
+    pub fn is_solved(&self, day: Day, part: u8) -> bool {
+        self.solved.contains_key(&(day, part))
+    }
+
+    /// Number of stars collected for a day.
+    #[must_use]
+    pub fn stars(&self, day: Day) -> usize {
+        [1, 2].iter().filter(|&&p| self.is_solved(day, p)).count()
+    }
+
+    #[must_use]
+    pub fn total_stars(&self) -> usize {
+        self.solved.len()
+    }
+
+    /// Update the store with the verdict for a submission.
+    /// Returns `true` if anything changed.
+    pub fn record_verdict(
+        &mut self,
+        day: Day,
+        part: u8,
+        answer: &str,
+        verdict: SubmitVerdict,
+    ) -> bool {
+        match verdict {
+            SubmitVerdict::Correct => {
+                self.solved.insert((day, part), Some(answer.to_string()));
+                true
+            }
+            // the level was solved before, but we can't tell if this answer is the accepted one.
+            SubmitVerdict::AlreadySolved if !self.is_solved(day, part) => {
+                self.solved.insert((day, part), None);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Record the accepted answer of a part, e.g. read from its solved puzzle description.
+    /// Only fills in parts whose answer is unknown. Returns `true` if anything changed.
+    pub fn record_accepted(&mut self, day: Day, part: u8, accepted: &str) -> bool {
+        match self.solved.get(&(day, part)) {
+            Some(Some(_)) => false,
+            _ => {
+                self.solved.insert((day, part), Some(accepted.to_string()));
+                true
+            }
+        }
+    }
+
+    /// Compare an answer to the accepted answer of a part.
+    #[must_use]
+    pub fn verify(&self, day: Day, part: u8, answer: &str) -> Verification {
+        match self.solved.get(&(day, part)) {
+            Some(Some(accepted)) if accepted == answer => Verification::Matches,
+            Some(Some(accepted)) => Verification::Differs(accepted.clone()),
+            _ => Verification::Unknown,
+        }
+    }
+}
+
+impl std::fmt::Display for Progress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for ((day, part), answer) in &self.solved {
+            match answer {
+                Some(answer) => writeln!(f, "{day} {part} {answer}")?,
+                None => writeln!(f, "{day} {part}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Progress, Verification};
+    use crate::day;
+    use crate::template::aoc_cli::SubmitVerdict;
+
+    #[test]
+    fn roundtrip() {
+        let s = "01 1 42\n01 2 1337\n03 1\n";
+        let progress = Progress::parse(s);
+        assert_eq!(progress.stars(day!(1)), 2);
+        assert_eq!(progress.stars(day!(2)), 0);
+        assert_eq!(progress.stars(day!(3)), 1);
+        assert_eq!(progress.total_stars(), 3);
+        assert_eq!(progress.to_string(), s);
+    }
+
+    #[test]
+    fn records_verdicts() {
+        let mut progress = Progress::default();
+        assert!(!progress.record_verdict(day!(5), 1, "1", SubmitVerdict::Incorrect));
+        assert!(progress.record_verdict(day!(5), 1, "2", SubmitVerdict::Correct));
+        assert!(!progress.record_verdict(day!(5), 1, "3", SubmitVerdict::AlreadySolved));
+        assert!(progress.record_verdict(day!(5), 2, "4", SubmitVerdict::AlreadySolved));
+
+        assert_eq!(progress.verify(day!(5), 1, "2"), Verification::Matches);
+        assert_eq!(
+            progress.verify(day!(5), 1, "3"),
+            Verification::Differs("2".into())
+        );
+        assert_eq!(progress.verify(day!(5), 2, "4"), Verification::Unknown);
+    }
+
+    #[test]
+    fn records_accepted_answers() {
+        let mut progress = Progress::parse("05 1 2\n05 2\n");
+        assert!(!progress.record_accepted(day!(5), 1, "3"));
+        assert!(progress.record_accepted(day!(5), 2, "4"));
+        assert!(progress.record_accepted(day!(6), 1, "5"));
+
+        assert_eq!(progress.verify(day!(5), 1, "2"), Verification::Matches);
+        assert_eq!(progress.verify(day!(5), 2, "4"), Verification::Matches);
+        assert_eq!(progress.to_string(), "05 1 2\n05 2 4\n06 1 5\n");
+    }
+}
@@ -1,7 +1,8 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{fs, io, str::FromStr, time::Duration};
 
+use crate::template::config;
 use crate::Day;
 
 static MARKER: &str = "<!--- benchmarking table --->";
@@ -18,26 +19,181 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[derive(Clone)]
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(s) => write!(f, "{s}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Mean duration of a benchmarked part and the number of samples it was computed from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PartTiming {
+    pub duration: Duration,
+    pub samples: u128,
+}
+
+#[derive(Clone, Debug)]
 pub struct Timings {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
-    pub total_nanos: f64,
+    pub parse: Option<Duration>,
+    pub part_1: Option<PartTiming>,
+    pub part_2: Option<PartTiming>,
+    /// Peak resident memory of the solution process, in bytes.
+    pub peak_memory: Option<u64>,
+    pub note: Option<String>,
+}
+
+impl Timings {
+    #[must_use]
+    pub fn new(day: Day) -> Self {
+        Self {
+            day,
+            parse: None,
+            part_1: None,
+            part_2: None,
+            peak_memory: None,
+            note: None,
+        }
+    }
+
+    /// Sum of both parts. The parse time is left out, the parts parse the input themselves.
+    #[must_use]
+    pub fn total(&self) -> Duration {
+        self.part_1.map(|p| p.duration).unwrap_or_default()
+            + self.part_2.map(|p| p.duration).unwrap_or_default()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Column {
+    Parse,
+    Part1,
+    Part2,
+    Total,
+    Memory,
+    Samples,
+    Note,
+}
+
+impl Column {
+    fn header(self) -> &'static str {
+        match self {
+            Column::Parse => "Parse",
+            Column::Part1 => "Part 1",
+            Column::Part2 => "Part 2",
+            Column::Total => "Total",
+            Column::Memory => "Memory",
+            Column::Samples => "Samples",
+            Column::Note => "Notes",
+        }
+    }
+
+    fn cell(self, timing: &Timings) -> String {
+        let duration = |d: Option<Duration>| d.map_or_else(|| "-".into(), format_duration);
+        match self {
+            // only days that register a parse step with `solution!` have a parse time.
+            Column::Parse => timing
+                .parse
+                .map_or_else(String::new, |d| format!("`{}`", format_duration(d))),
+            Column::Part1 => format!("`{}`", duration(timing.part_1.map(|p| p.duration))),
+            Column::Part2 => format!("`{}`", duration(timing.part_2.map(|p| p.duration))),
+            Column::Total => format!("`{}`", format_duration(timing.total())),
+            Column::Memory => format!(
+                "`{}`",
+                timing.peak_memory.map_or_else(|| "-".into(), format_bytes)
+            ),
+            Column::Samples => {
+                let samples =
+                    |p: Option<PartTiming>| p.map_or_else(|| "-".into(), |p| p.samples.to_string());
+                format!("`{} / {}`", samples(timing.part_1), samples(timing.part_2))
+            }
+            Column::Note => timing.note.clone().unwrap_or_default(),
+        }
+    }
+}
+
+impl FromStr for Column {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Column::Parse),
+            "part1" => Ok(Column::Part1),
+            "part2" => Ok(Column::Part2),
+            "total" => Ok(Column::Total),
+            "memory" => Ok(Column::Memory),
+            "samples" => Ok(Column::Samples),
+            "notes" => Ok(Column::Note),
+            _ => Err(Error::Parser(format!(
+                "unknown column \"{s}\", expecting one of parse, part1, part2, total, memory, samples, notes."
+            ))),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortOrder {
+    Day,
+    Slowest,
+    Fastest,
+}
+
+impl FromStr for SortOrder {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "day" => Ok(SortOrder::Day),
+            "slowest" => Ok(SortOrder::Slowest),
+            "fastest" => Ok(SortOrder::Fastest),
+            _ => Err(Error::Parser(format!(
+                "unknown sort order \"{s}\", expecting one of day, slowest, fastest."
+            ))),
+        }
+    }
+}
+
+/// Controls the layout of the benchmark table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableOptions {
+    pub columns: Vec<Column>,
+    pub sort: SortOrder,
+    /// Number of days, by total time, that are marked as the slowest.
+    pub mark_slowest: usize,
+    /// Path of a chart image that is embedded below the table.
+    pub chart: Option<String>,
+}
+
+impl Default for TableOptions {
+    fn default() -> Self {
+        Self {
+            columns: vec![Column::Part1, Column::Part2],
+            sort: SortOrder::Day,
+            mark_slowest: 0,
+            chart: None,
+        }
+    }
 }
 
 pub struct TablePosition {
-    pos_start: usize,
-    pos_end: usize,
+    pub pos_start: usize,
+    pub pos_end: usize,
 }
 
+/// Link target of a day in the table, the file its solution lives in.
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_solution(day: Day) -> String {
+    format!("./{}", config::get().solution_path(day).display())
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+/// Locates the section between the first and last occurrence of `marker`, including the markers.
+pub fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -58,75 +214,126 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> String {
+fn format_duration(duration: Duration) -> String {
+    format!("{duration:.1?}")
+}
+
+fn format_bytes(bytes: u64) -> String {
+    #[allow(clippy::cast_precision_loss)]
+    let bytes = bytes as f64;
+    match bytes {
+        b if b >= 1024.0 * 1024.0 * 1024.0 => format!("{:.1} GiB", b / (1024.0 * 1024.0 * 1024.0)),
+        b if b >= 1024.0 * 1024.0 => format!("{:.1} MiB", b / (1024.0 * 1024.0)),
+        b if b >= 1024.0 => format!("{:.1} KiB", b / 1024.0),
+        b => format!("{b} B"),
+    }
+}
+
+fn construct_table(prefix: &str, mut timings: Vec<Timings>, options: &TableOptions) -> String {
     let header = format!("{prefix} Benchmarks");
+    let total_millis = timings
+        .iter()
+        .map(Timings::total)
+        .sum::<Duration>()
+        .as_secs_f64()
+        * 1000.0;
+
+    let mut by_total: Vec<_> = timings.iter().map(|t| (t.total(), t.day)).collect();
+    by_total.sort_by(|a, b| b.cmp(a));
+    let slowest: Vec<Day> = by_total
+        .into_iter()
+        .take(options.mark_slowest)
+        .map(|(_, day)| day)
+        .collect();
+
+    match options.sort {
+        SortOrder::Day => timings.sort_by_key(|t| t.day),
+        SortOrder::Slowest => timings.sort_by_key(|t| std::cmp::Reverse(t.total())),
+        SortOrder::Fastest => timings.sort_by_key(Timings::total),
+    }
+
+    let headers: Vec<&str> = options.columns.iter().map(|c| c.header()).collect();
 
     let mut lines: Vec<String> = vec![
         MARKER.into(),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        format!("| Day | {} |", headers.join(" | ")),
+        format!("| :---: |{}", " :---: |".repeat(headers.len())),
     ];
 
     for timing in timings {
-        let path = get_path_for_bin(timing.day);
+        let path = get_path_for_solution(timing.day);
+        let mark = if slowest.contains(&timing.day) {
+            " 🐢"
+        } else {
+            ""
+        };
+        let cells: Vec<String> = options.columns.iter().map(|c| c.cell(&timing)).collect();
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+            "| [Day {}]({}){mark} | {} |",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            cells.join(" | ")
         ));
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
+
+    if let Some(chart) = &options.chart {
+        lines.push(String::new());
+        lines.push(format!("![Benchmark chart](./{chart})"));
+    }
+
     lines.push(MARKER.into());
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+fn update_content(
+    s: &mut String,
+    timings: Vec<Timings>,
+    options: &TableOptions,
+) -> Result<(), Error> {
+    let positions = locate_table(s, MARKER)?;
+    let table = construct_table("##", timings, options);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
-    let path = "README.md";
+pub fn update(timings: Vec<Timings>, options: &TableOptions) -> Result<(), Error> {
+    let path = &config::get().paths.readme;
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, timings, options)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, Timings, MARKER};
-    use crate::day;
+    use std::time::Duration;
+
+    use super::{update_content, Column, PartTiming, SortOrder, TableOptions, Timings, MARKER};
+    use crate::{day, Day};
+
+    fn get_mock_timing(day: Day, part_1_millis: u64, part_2_millis: u64) -> Timings {
+        let part = |millis| PartTiming {
+            duration: Duration::from_millis(millis),
+            samples: 10,
+        };
+        Timings {
+            part_1: Some(part(part_1_millis)),
+            part_2: Some(part(part_2_millis)),
+            ..Timings::new(day)
+        }
+    }
 
     fn get_mock_timings() -> Vec<Timings> {
         vec![
-            Timings {
-                day: day!(1),
-                part_1: Some("10ms".into()),
-                part_2: Some("20ms".into()),
-                total_nanos: 3e+10,
-            },
-            Timings {
-                day: day!(2),
-                part_1: Some("30ms".into()),
-                part_2: Some("40ms".into()),
-                total_nanos: 7e+10,
-            },
-            Timings {
-                day: day!(4),
-                part_1: Some("40ms".into()),
-                part_2: Some("50ms".into()),
-                total_nanos: 9e+10,
-            },
+            get_mock_timing(day!(1), 10, 20),
+            get_mock_timing(day!(2), 30, 40),
+            get_mock_timing(day!(4), 40, 50),
         ]
     }
 
@@ -134,28 +341,28 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), &TableOptions::default()).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), &TableOptions::default()).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), &TableOptions::default()).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), &TableOptions::default()).unwrap();
+        update_content(&mut s, get_mock_timings(), &TableOptions::default()).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -163,7 +370,7 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), &TableOptions::default()).unwrap();
         let expected = [
             "foo",
             "bar",
@@ -171,10 +378,10 @@ mod tests {
             "## Benchmarks",
             "",
             "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](./src/y2023/day01.rs) | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/y2023/day02.rs) | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/y2023/day04.rs) | `40.0ms` | `50.0ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -183,4 +390,46 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_configured_benchmarks() {
+        let mut timings = get_mock_timings();
+        timings[0].parse = Some(Duration::from_millis(5));
+        timings[0].peak_memory = Some(3 * 1024 * 1024);
+        timings[1].note = Some("brute force".into());
+        timings[2].part_2 = None;
+
+        let options = TableOptions {
+            columns: vec![
+                Column::Parse,
+                Column::Total,
+                Column::Memory,
+                Column::Samples,
+                Column::Note,
+            ],
+            sort: SortOrder::Slowest,
+            mark_slowest: 1,
+            chart: Some(".assets/benchmarks.svg".into()),
+        };
+
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, timings, &options).unwrap();
+        let expected = [
+            "<!--- benchmarking table --->",
+            "## Benchmarks",
+            "",
+            "| Day | Parse | Total | Memory | Samples | Notes |",
+            "| :---: | :---: | :---: | :---: | :---: | :---: |",
+            "| [Day 2](./src/y2023/day02.rs) 🐢 |  | `70.0ms` | `-` | `10 / 10` | brute force |",
+            "| [Day 4](./src/y2023/day04.rs) |  | `40.0ms` | `-` | `10 / -` |  |",
+            "| [Day 1](./src/y2023/day01.rs) | `5.0ms` | `30.0ms` | `3.0 MiB` | `10 / 10` |  |",
+            "",
+            "**Total: 140.00ms**",
+            "",
+            "![Benchmark chart](./.assets/benchmarks.svg)",
+            "<!--- benchmarking table --->",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
 }
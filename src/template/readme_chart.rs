@@ -0,0 +1,256 @@
+/// Module that renders benchmark timings as an SVG bar chart, embedded in the readme next to the benchmark table.
+/// Durations span several orders of magnitude, so bars are drawn on a log scale.
+use std::{fs, io, path::Path, time::Duration};
+
+use crate::template::readme_benchmarks::Timings;
+
+pub const CHART_PATH: &str = ".assets/benchmarks.svg";
+
+const WIDTH: f64 = 800.0;
+const MARGIN_LEFT: f64 = 70.0;
+const MARGIN_RIGHT: f64 = 20.0;
+const MARGIN_TOP: f64 = 40.0;
+const MARGIN_BOTTOM: f64 = 40.0;
+const ROW_HEIGHT: f64 = 28.0;
+const BAR_HEIGHT: f64 = 10.0;
+
+const COLOR_PART_1: &str = "#4e79a7";
+const COLOR_PART_2: &str = "#f28e2b";
+const COLOR_AXIS: &str = "#888888";
+
+/// Minimal SVG writer, only supports the elements needed for the charts.
+pub(crate) struct Svg {
+    width: f64,
+    height: f64,
+    elements: Vec<String>,
+}
+
+impl Svg {
+    pub(crate) fn new(width: f64, height: f64) -> Self {
+        Self {
+            width,
+            height,
+            elements: vec![],
+        }
+    }
+
+    pub(crate) fn rect(&mut self, x: f64, y: f64, width: f64, height: f64, fill: &str) {
+        self.elements.push(format!(
+            r#"<rect x="{x:.1}" y="{y:.1}" width="{width:.1}" height="{height:.1}" fill="{fill}"/>"#
+        ));
+    }
+
+    pub(crate) fn line(&mut self, x1: f64, y1: f64, x2: f64, y2: f64, stroke: &str) {
+        self.elements.push(format!(
+            r#"<line x1="{x1:.1}" y1="{y1:.1}" x2="{x2:.1}" y2="{y2:.1}" stroke="{stroke}" stroke-width="1"/>"#
+        ));
+    }
+
+    pub(crate) fn text(&mut self, x: f64, y: f64, anchor: &str, text: &str) {
+        self.elements.push(format!(
+            r#"<text x="{x:.1}" y="{y:.1}" text-anchor="{anchor}">{}</text>"#,
+            escape(text)
+        ));
+    }
+
+    /// A closed shape through the given points.
+    pub(crate) fn polygon(&mut self, points: &[(f64, f64)], fill: &str) {
+        let points: Vec<String> = points
+            .iter()
+            .map(|(x, y)| format!("{x:.1},{y:.1}"))
+            .collect();
+        self.elements.push(format!(
+            r#"<polygon points="{}" fill="{fill}"/>"#,
+            points.join(" ")
+        ));
+    }
+}
+
+impl std::fmt::Display for Svg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" font-family="sans-serif" font-size="12">"#,
+            w = self.width,
+            h = self.height
+        )?;
+        for element in &self.elements {
+            writeln!(f, "  {element}")?;
+        }
+        writeln!(f, "</svg>")
+    }
+}
+
+pub(crate) fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Maps durations to horizontal positions on a log scale spanning whole powers of ten.
+struct LogScale {
+    min_exponent: i32,
+    max_exponent: i32,
+}
+
+impl LogScale {
+    fn new(durations: &[Duration]) -> Self {
+        let exponents = durations
+            .iter()
+            .map(|d| nanos(*d).max(1.0).log10())
+            .collect::<Vec<_>>();
+
+        #[allow(clippy::cast_possible_truncation)]
+        let min_exponent = exponents
+            .iter()
+            .copied()
+            .fold(f64::INFINITY, f64::min)
+            .floor() as i32;
+        #[allow(clippy::cast_possible_truncation)]
+        let max_exponent = exponents
+            .iter()
+            .copied()
+            .fold(f64::NEG_INFINITY, f64::max)
+            .ceil() as i32;
+
+        Self {
+            min_exponent,
+            max_exponent: max_exponent.max(min_exponent + 1),
+        }
+    }
+
+    fn position(&self, exponent: f64) -> f64 {
+        let span = f64::from(self.max_exponent - self.min_exponent);
+        MARGIN_LEFT
+            + (exponent - f64::from(self.min_exponent)) / span
+                * (WIDTH - MARGIN_LEFT - MARGIN_RIGHT)
+    }
+
+    fn x(&self, duration: Duration) -> f64 {
+        self.position(nanos(duration).max(1.0).log10())
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn nanos(duration: Duration) -> f64 {
+    duration.as_nanos() as f64
+}
+
+/// Renders the chart, returns `None` if there is nothing to draw.
+#[must_use]
+pub fn render(timings: &[Timings]) -> Option<String> {
+    let durations: Vec<Duration> = timings
+        .iter()
+        .flat_map(|t| [t.part_1, t.part_2])
+        .flatten()
+        .map(|p| p.duration)
+        .collect();
+
+    if durations.is_empty() {
+        return None;
+    }
+
+    let scale = LogScale::new(&durations);
+    #[allow(clippy::cast_precision_loss)]
+    let chart_height = ROW_HEIGHT * timings.len() as f64;
+    let mut svg = Svg::new(WIDTH, MARGIN_TOP + chart_height + MARGIN_BOTTOM);
+
+    // legend
+    svg.rect(MARGIN_LEFT, 12.0, 12.0, 12.0, COLOR_PART_1);
+    svg.text(MARGIN_LEFT + 18.0, 22.0, "start", "Part 1");
+    svg.rect(MARGIN_LEFT + 80.0, 12.0, 12.0, 12.0, COLOR_PART_2);
+    svg.text(MARGIN_LEFT + 98.0, 22.0, "start", "Part 2");
+
+    // grid lines and labels for every power of ten
+    let axis_y = MARGIN_TOP + chart_height;
+    for exponent in scale.min_exponent..=scale.max_exponent {
+        let x = scale.position(f64::from(exponent));
+        svg.line(x, MARGIN_TOP, x, axis_y, COLOR_AXIS);
+        let label = format!(
+            "{:?}",
+            Duration::from_nanos(10_u64.pow(exponent.unsigned_abs()))
+        );
+        svg.text(x, axis_y + 16.0, "middle", &label);
+    }
+
+    for (row, timing) in timings.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let y = MARGIN_TOP + ROW_HEIGHT * row as f64;
+        svg.text(
+            MARGIN_LEFT - 8.0,
+            y + ROW_HEIGHT / 2.0 + 4.0,
+            "end",
+            &format!("Day {}", timing.day),
+        );
+
+        let parts = [(timing.part_1, COLOR_PART_1), (timing.part_2, COLOR_PART_2)];
+        for (i, (part, color)) in parts.iter().enumerate() {
+            if let Some(part) = part {
+                #[allow(clippy::cast_precision_loss)]
+                let bar_y = y + 4.0 + BAR_HEIGHT * i as f64;
+                let width = scale.x(part.duration) - MARGIN_LEFT;
+                svg.rect(MARGIN_LEFT, bar_y, width.max(1.0), BAR_HEIGHT, color);
+            }
+        }
+    }
+
+    svg.line(
+        MARGIN_LEFT,
+        axis_y,
+        WIDTH - MARGIN_RIGHT,
+        axis_y,
+        COLOR_AXIS,
+    );
+
+    Some(svg.to_string())
+}
+
+/// Writes the chart to [`CHART_PATH`]. Returns `false` if there was nothing to draw.
+pub fn update(timings: &[Timings]) -> Result<bool, io::Error> {
+    let Some(svg) = render(timings) else {
+        return Ok(false);
+    };
+
+    if let Some(dir) = Path::new(CHART_PATH).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(CHART_PATH, svg)?;
+    Ok(true)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{render, LogScale, MARGIN_LEFT, MARGIN_RIGHT, WIDTH};
+    use crate::day;
+    use crate::template::readme_benchmarks::{PartTiming, Timings};
+
+    #[test]
+    fn log_scale_spans_powers_of_ten() {
+        let scale = LogScale::new(&[Duration::from_nanos(50), Duration::from_millis(2)]);
+        assert_eq!(scale.min_exponent, 1);
+        assert_eq!(scale.max_exponent, 7);
+        assert!((scale.x(Duration::from_nanos(10)) - MARGIN_LEFT).abs() < 1e-9);
+        assert!((scale.x(Duration::from_millis(10)) - (WIDTH - MARGIN_RIGHT)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn renders_bars_per_part() {
+        let timings = vec![Timings {
+            part_1: Some(PartTiming {
+                duration: Duration::from_micros(10),
+                samples: 10,
+            }),
+            ..Timings::new(day!(1))
+        }];
+
+        let svg = render(&timings).unwrap();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("Day 01"));
+        // legend and a single bar.
+        assert_eq!(svg.matches("<rect").count(), 3);
+
+        assert!(render(&[Timings::new(day!(2))]).is_none());
+    }
+}
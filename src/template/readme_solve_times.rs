@@ -0,0 +1,96 @@
+/// Module that updates the readme with the personal solve times from the local solve log.
+use std::fs;
+
+use crate::template::{
+    config,
+    readme_benchmarks::{locate_table, Error},
+    solve_log::{format_duration, DayStats},
+};
+use crate::Day;
+
+static MARKER: &str = "<!--- solve times table --->";
+
+/// Part one time, and part two as the time after solving part one, e.g. `+0:05:00`.
+#[must_use]
+pub fn format_times(stats: &DayStats) -> (String, String) {
+    (
+        stats.part_1.map_or_else(|| "-".into(), format_duration),
+        stats
+            .part_2
+            .map_or_else(|| "-".into(), |d| format!("+{}", format_duration(d))),
+    )
+}
+
+fn construct_table(stats: &[(Day, DayStats)]) -> String {
+    let mut lines: Vec<String> = vec![
+        MARKER.into(),
+        "## Solve Times".into(),
+        String::new(),
+        "| Day | Part 1 | Part 2 | Wrong answers |".into(),
+        "| :---: | :---: | :---: | :---: |".into(),
+    ];
+
+    for (day, stats) in stats {
+        let (part_1, part_2) = format_times(stats);
+        lines.push(format!(
+            "| Day {} | {part_1} | {part_2} | {} |",
+            day.into_inner(),
+            stats.wrong_attempts
+        ));
+    }
+
+    lines.push(MARKER.into());
+    lines.join("\n")
+}
+
+fn update_content(s: &mut String, stats: &[(Day, DayStats)]) -> Result<(), Error> {
+    let positions = locate_table(s, MARKER)?;
+    let table = construct_table(stats);
+    s.replace_range(positions.pos_start..positions.pos_end, &table);
+    Ok(())
+}
+
+pub fn update(stats: &[(Day, DayStats)]) -> Result<(), Error> {
+    let path = &config::get().paths.readme;
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    update_content(&mut readme, stats)?;
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{update_content, MARKER};
+    use crate::day;
+    use crate::template::solve_log::DayStats;
+
+    #[test]
+    fn format_solve_times() {
+        let stats = [(
+            day!(4),
+            DayStats {
+                part_1: Some(Duration::from_secs(754)),
+                part_2: None,
+                wrong_attempts: 2,
+            },
+        )];
+
+        let mut s = format!("foo\n{MARKER}\n{MARKER}\nbar");
+        update_content(&mut s, &stats).unwrap();
+        let expected = [
+            "foo",
+            "<!--- solve times table --->",
+            "## Solve Times",
+            "",
+            "| Day | Part 1 | Part 2 | Wrong answers |",
+            "| :---: | :---: | :---: | :---: |",
+            "| Day 4 | 0:12:34 | - | 2 |",
+            "<!--- solve times table --->",
+            "bar",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+}
@@ -0,0 +1,129 @@
+/// Module that updates the readme with the star progress from the local progress store.
+/// The generated table mirrors the one written by the `readme-stars.yml` workflow, but works offline.
+use std::fs;
+
+use crate::template::{
+    config,
+    progress::Progress,
+    readme_benchmarks::{locate_table, Error},
+};
+use crate::{all_days, Day};
+
+static MARKER: &str = "<!--- advent_readme_stars table --->";
+
+fn format_star(progress: &Progress, day: Day, part: u8) -> &'static str {
+    if progress.is_solved(day, part) {
+        "⭐"
+    } else {
+        " "
+    }
+}
+
+fn construct_table(progress: &Progress, year: Option<u16>) -> String {
+    let header = match year {
+        Some(year) => format!("## {year} Results"),
+        None => "## Results".into(),
+    };
+
+    let mut lines: Vec<String> = vec![
+        MARKER.into(),
+        header,
+        String::new(),
+        "| Day | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: |".into(),
+    ];
+
+    for day in all_days().filter(|&day| progress.stars(day) > 0) {
+        let label = match year {
+            Some(year) => format!(
+                "[Day {}](https://adventofcode.com/{year}/day/{})",
+                day.into_inner(),
+                day.into_inner()
+            ),
+            None => format!("Day {}", day.into_inner()),
+        };
+
+        lines.push(format!(
+            "| {label} | {} | {} |",
+            format_star(progress, day, 1),
+            format_star(progress, day, 2)
+        ));
+    }
+
+    lines.push(MARKER.into());
+    lines.join("\n")
+}
+
+fn update_content(s: &mut String, progress: &Progress, year: Option<u16>) -> Result<(), Error> {
+    let positions = locate_table(s, MARKER)?;
+    let table = construct_table(progress, year);
+    s.replace_range(positions.pos_start..positions.pos_end, &table);
+    Ok(())
+}
+
+pub fn update(progress: &Progress, year: Option<u16>) -> Result<(), Error> {
+    let path = &config::get().paths.readme;
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    update_content(&mut readme, progress, year)?;
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+/// Renders the 25 days of advent as a calendar of five weeks.
+#[must_use]
+pub fn render_calendar(progress: &Progress) -> String {
+    let days: Vec<String> = all_days()
+        .map(|day| {
+            let stars = match progress.stars(day) {
+                2 => "⭐⭐",
+                1 => "⭐ ·",
+                _ => "· · ",
+            };
+            format!("{day} {stars}")
+        })
+        .collect();
+
+    let mut lines: Vec<String> = days.chunks(5).map(|week| week.join("  ")).collect();
+    lines.push(String::new());
+    lines.push(format!("Stars: {}/50", progress.total_stars()));
+    lines.join("\n")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{update_content, Progress, MARKER};
+    use crate::day;
+    use crate::template::aoc_cli::SubmitVerdict;
+
+    fn get_mock_progress() -> Progress {
+        let mut progress = Progress::default();
+        progress.record_verdict(day!(1), 1, "1", SubmitVerdict::Correct);
+        progress.record_verdict(day!(1), 2, "2", SubmitVerdict::Correct);
+        progress.record_verdict(day!(3), 1, "3", SubmitVerdict::Correct);
+        progress
+    }
+
+    #[test]
+    fn format_stars() {
+        let mut s = format!("foo\n{}\nbar", MARKER);
+        update_content(&mut s, &get_mock_progress(), Some(2023)).unwrap();
+        let expected = [
+            "foo",
+            "<!--- advent_readme_stars table --->",
+            "## 2023 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2023/day/1) | ⭐ | ⭐ |",
+            "| [Day 3](https://adventofcode.com/2023/day/3) | ⭐ |   |",
+            "<!--- advent_readme_stars table --->",
+            "bar",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+
+        // the second run replaces the table between both markers.
+        update_content(&mut s, &get_mock_progress(), Some(2023)).unwrap();
+        assert_eq!(s, expected);
+    }
+}
@@ -1,61 +1,254 @@
 /// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
+use crate::template::cli::SolutionArgs;
+use crate::template::progress::{Progress, Verification};
+use crate::template::solve_log::{self, Entry};
+use crate::template::{
+    aoc_cli, config, get_data_path, input, markdown, Normalization, ANSI_ITALIC, ANSI_RESET,
+};
 use crate::Day;
 use std::fmt::Display;
-use std::io::{stdout, Write};
-use std::process::Output;
+use std::io::{stdin, stdout, IsTerminal, Write};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, process};
 
 use super::ANSI_BOLD;
 
+/// Read and normalise the puzzle input for a day, checking it before any solution code runs.
+/// If the input is missing or unusable, offer to download it when attached to a terminal, exit otherwise.
+pub fn read_input(day: Day, normalization: Normalization) -> String {
+    let path = get_data_path("inputs", day, 0);
+    let mut offered_download = false;
+
+    loop {
+        match input::load(&path, normalization) {
+            Ok(checked) => {
+                for warning in checked.warnings {
+                    eprintln!("Warning: \"{}\": {warning}", path.display());
+                }
+                return checked.contents;
+            }
+            Err(issue) => {
+                eprintln!("Input \"{}\": {issue}", path.display());
+
+                if offered_download || !prompt_download(day) {
+                    eprintln!(
+                        "Add your puzzle input to this file or run \"cargo download {day}\"."
+                    );
+                    process::exit(1);
+                }
+
+                offered_download = true;
+            }
+        }
+    }
+}
+
+/// Ask whether the input should be downloaded and do so.
+/// Returns `true` if the download succeeded.
+fn prompt_download(day: Day) -> bool {
+    if !stdin().is_terminal() || !stdout().is_terminal() || aoc_cli::check().is_err() {
+        return false;
+    }
+
+    print!("Download the input for day {day} via aoc-cli? [y/N] ");
+    let _ = stdout().flush();
+
+    let mut answer = String::new();
+    if stdin().read_line(&mut answer).is_err() || !answer.trim().eq_ignore_ascii_case("y") {
+        return false;
+    }
+
+    match aoc_cli::download(day) {
+        Ok(_) => true,
+        Err(e) => {
+            eprintln!("failed to call aoc-cli: {e}");
+            false
+        }
+    }
+}
+
+/// Label of the line with the parse time of a day.
+pub const PARSE_LABEL: &str = "Parse";
+
+/// Run the parse step a solution registered with `solution!(day, parse = parse)`, timed like a part.
+/// The parts still parse the input themselves, this only shows how much of their time parsing takes.
+pub fn run_parse<'a, T>(func: impl Fn(&'a str) -> T, input: &'a str) {
+    let (_, timers) = run_timed(func, input, |_| {});
+    print!("\r");
+    println!("{PARSE_LABEL}:{}", format_duration(&timers));
+}
+
+/// Label of the line with the raw bench samples of a part, printed with `--samples`.
+pub const SAMPLES_LABEL: &str = "samples (ns):";
+
 pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, timers) = run_timed(func, input, |result| print_result(result, &part_str, ""));
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    print_result(&result, &part_str, &format_duration(&timers));
+
+    if SolutionArgs::get().samples && timers.len() > 1 {
+        print_samples(&timers, part);
+    }
 
     if let Some(result) = result {
+        verify_result(&result, day, part);
         submit_result(result, day, part);
     }
 }
 
+/// A named implementation of a part, registered with the `variants` argument of `solution!`.
+pub struct Variant<'a, T> {
+    pub name: &'static str,
+    pub func: &'a dyn Fn(&str) -> Option<T>,
+}
+
+/// Run every variant of a part, check that they agree and compare their timings to the first variant.
+/// Results are not submitted in this mode. Returns whether all variants agree.
+pub fn run_variants<T: Display>(variants: &[Variant<T>], input: &str, part: u8) -> bool {
+    let mut runs = vec![];
+
+    for variant in variants {
+        let part_str = format!("Part {part} [{}]", variant.name);
+        let (result, timers) = run_timed(variant.func, input, |result| {
+            print_result(result, &part_str, "");
+        });
+        print_result(&result, &part_str, &format_duration(&timers));
+        runs.push((
+            variant.name,
+            result.map(|r| r.to_string()),
+            average_duration(&timers),
+        ));
+    }
+
+    // a single variant has nothing to compare against.
+    let [(_, expected, baseline), ..] = runs.as_slice() else {
+        return true;
+    };
+    if runs.len() < 2 {
+        return true;
+    }
+
+    let agree = runs.iter().all(|(_, result, _)| result == expected);
+    if agree {
+        println!("✔ {} variants agree", runs.len());
+    } else {
+        println!("✖ variants disagree");
+    }
+
+    let name_width = runs
+        .iter()
+        .map(|(name, _, _)| name.len())
+        .max()
+        .unwrap_or(0);
+    println!(
+        "  {:name_width$}  {:>12}  {:>9}",
+        "variant", "time", "speed-up"
+    );
+    for (name, _, duration) in &runs {
+        let speed_up = baseline.as_secs_f64() / duration.as_secs_f64().max(f64::EPSILON);
+        println!(
+            "  {name:name_width$}  {:>12}  {:>8.2}x",
+            format!("{duration:.1?}"),
+            speed_up
+        );
+    }
+
+    agree
+}
+
+/// Warn if a result differs from the answer accepted for this part before.
+/// The accepted answer shown in a solved puzzle description is recorded first if the store lacks it.
+fn verify_result<T: Display>(result: &T, day: Day, part: u8) {
+    let Ok(mut progress) = Progress::load() else {
+        return;
+    };
+
+    let accepted = std::fs::read_to_string(config::get().puzzle_path(day))
+        .ok()
+        .and_then(|puzzle| markdown::accepted_answer(&puzzle, part));
+    if let Some(accepted) = accepted {
+        if progress.record_accepted(day, part, &accepted) {
+            if let Err(e) = progress.save() {
+                eprintln!("Failed to save progress: {e}");
+            }
+        }
+    }
+
+    if let Verification::Differs(accepted) = progress.verify(day, part, &result.to_string()) {
+        eprintln!("Warning: part {part} differs from the accepted answer {accepted}.");
+    }
+}
+
+/// Print the peak memory usage of the process when benching, so `all` can pick it up.
+/// Only supported on Linux, where it is read from `/proc/self/status`.
+pub fn print_peak_memory() {
+    if !SolutionArgs::get().time {
+        return;
+    }
+
+    if let Some(kilobytes) = read_peak_memory() {
+        println!("Peak memory: {kilobytes} kB");
+    }
+}
+
+fn read_peak_memory() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    status
+        .lines()
+        .find_map(|l| l.strip_prefix("VmHWM:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Print the time of every sample, so `bench-report` can plot their distribution.
+fn print_samples(timers: &[Duration], part: u8) {
+    let nanos: Vec<String> = timers.iter().map(|t| t.as_nanos().to_string()).collect();
+    println!("Part {part} {SAMPLES_LABEL} {}", nanos.join(" "));
+}
+
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///     The budget is configured in the `[bench]` section of `aoc.toml`.
+///
+/// Returns the result and the time of each run.
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, Vec<Duration>) {
     let timer = Instant::now();
     let result = func(input.clone());
     let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
+    let timers = if SolutionArgs::get().time {
         bench(func, input, &base_time)
     } else {
-        (base_time, 1)
+        vec![base_time]
     };
 
-    (result, run.0, run.1)
+    (result, timers)
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> Vec<Duration> {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
+    let bench = config::get().bench;
     let bench_iterations = cmp::min(
-        10000,
+        bench.max_samples,
         cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
+            bench.budget.as_nanos() / cmp::max(base_time.as_nanos(), 10),
+            bench.min_samples,
         ),
     );
 
@@ -69,22 +262,24 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    timers
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
+fn average_duration(numbers: &[Duration]) -> Duration {
+    let nanos = numbers
         .iter()
         .map(std::time::Duration::as_nanos)
         .sum::<u128>()
-        / numbers.len() as u128
+        / numbers.len() as u128;
+
+    #[allow(clippy::cast_possible_truncation)]
+    Duration::from_nanos(nanos as u64)
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
+fn format_duration(timers: &[Duration]) -> String {
+    let duration = average_duration(timers);
+    let samples = timers.len();
+
     if samples == 1 {
         format!(" ({duration:.1?})")
     } else {
@@ -127,41 +322,55 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
+/// Try to submit one part of the solution if:
+///  1. the part was passed to `--submit`.
 ///  2. aoc-cli is installed.
+///
+/// The verdict is recorded in the local progress store.
 fn submit_result<T: Display>(
     result: T,
     day: Day,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
-
-    if !args.contains(&"--submit".into()) {
+) -> Option<Result<aoc_cli::SubmitVerdict, aoc_cli::AocCommandError>> {
+    if SolutionArgs::get().submit != Some(part) {
         return None;
     }
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+    if aoc_cli::check().is_err() {
+        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
         process::exit(1);
     }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
-
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
-    };
+    println!("Submitting result via aoc-cli...");
+    let answer = result.to_string();
+    let verdict = aoc_cli::submit(day, part, &answer);
 
-    if part_submit != part {
-        return None;
+    if let Ok(verdict) = verdict {
+        record_verdict(day, part, &answer, verdict);
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    Some(verdict)
+}
+
+fn record_verdict(day: Day, part: u8, answer: &str, verdict: aoc_cli::SubmitVerdict) {
+    if let Some(entry) = Entry::from_verdict(day, part, verdict) {
+        if let Err(e) = solve_log::append(entry) {
+            eprintln!("Failed to record the submission: {e}");
+        }
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    let mut progress = match Progress::load() {
+        Ok(progress) => progress,
+        Err(e) => {
+            eprintln!("Failed to read progress: {e}");
+            return;
+        }
+    };
+
+    if progress.record_verdict(day, part, answer, verdict) {
+        match progress.save() {
+            Ok(()) => println!("🎄 Recorded ⭐ for day {day}, part {part}."),
+            Err(e) => eprintln!("Failed to save progress: {e}"),
+        }
+    }
 }
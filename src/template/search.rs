@@ -0,0 +1,294 @@
+/// Full text search over the saved puzzle descriptions and the solution sources.
+/// The index is built in memory on every search, reading 50 small files is faster than keeping an index up to date.
+use std::fs;
+
+use crate::template::{config::Config, tags::Tags, ANSI_BOLD, ANSI_RESET};
+use crate::{all_days, Day};
+
+/// Maximum width of a snippet, in characters.
+const SNIPPET_WIDTH: usize = 80;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Source {
+    Puzzle,
+    Solution,
+}
+
+impl Source {
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Source::Puzzle => "puzzle",
+            Source::Solution => "solution",
+        }
+    }
+}
+
+struct Document {
+    day: Day,
+    source: Source,
+    text: String,
+}
+
+/// A matching line of a document.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Snippet {
+    pub source: Source,
+    pub line: usize,
+    /// Excerpt of the line with the search terms in bold.
+    pub text: String,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Hit {
+    pub day: Day,
+    pub tags: Vec<String>,
+    /// Number of occurrences of the search terms, used for ranking.
+    pub score: usize,
+    pub snippets: Vec<Snippet>,
+}
+
+#[derive(Default)]
+pub struct Index {
+    documents: Vec<Document>,
+    tags: Tags,
+}
+
+impl Index {
+    /// Index the puzzle descriptions and solutions of all days, skipping files that don't exist.
+    #[must_use]
+    pub fn load(config: &Config, tags: Tags) -> Self {
+        let mut index = Self {
+            documents: vec![],
+            tags,
+        };
+
+        for day in all_days() {
+            // solutions of past events live in the library, their binaries only call into it.
+            let sources = [
+                (Source::Puzzle, Some(config.puzzle_path(day))),
+                (Source::Solution, Some(config.bin_path(day))),
+                (Source::Solution, config.module_path(day)),
+            ];
+
+            for (source, path) in sources {
+                let Some(path) = path else { continue };
+                if let Ok(text) = fs::read_to_string(path) {
+                    index.add(day, source, text);
+                }
+            }
+        }
+
+        index
+    }
+
+    fn add(&mut self, day: Day, source: Source, text: String) {
+        self.documents.push(Document { day, source, text });
+    }
+
+    /// Days that contain every term and have every tag of the filter, best matches first.
+    /// Terms are matched case-insensitively against the documents and the tags of a day.
+    #[must_use]
+    pub fn search(&self, terms: &[String], filter: &[String]) -> Vec<Hit> {
+        let terms: Vec<String> = terms.iter().map(|t| t.to_ascii_lowercase()).collect();
+
+        let mut hits: Vec<Hit> = all_days()
+            .filter(|&day| self.tags.matches(day, filter))
+            .filter_map(|day| self.search_day(day, &terms))
+            .collect();
+
+        hits.sort_by(|a, b| b.score.cmp(&a.score).then(a.day.cmp(&b.day)));
+        hits
+    }
+
+    fn search_day(&self, day: Day, terms: &[String]) -> Option<Hit> {
+        let tags = self.tags.get(day).to_vec();
+        let documents: Vec<&Document> = self.documents.iter().filter(|d| d.day == day).collect();
+
+        if documents.is_empty() && tags.is_empty() {
+            return None;
+        }
+
+        let mut score = 0;
+        for term in terms {
+            let tag_count = tags
+                .iter()
+                .filter(|tag| tag.contains(term.as_str()))
+                .count();
+            let text_count: usize = documents
+                .iter()
+                .map(|d| d.text.to_ascii_lowercase().matches(term.as_str()).count())
+                .sum();
+
+            if tag_count + text_count == 0 {
+                return None;
+            }
+            score += tag_count + text_count;
+        }
+
+        let snippets = documents.iter().filter_map(|d| snippet(d, terms)).collect();
+
+        Some(Hit {
+            day,
+            tags,
+            score,
+            snippets,
+        })
+    }
+}
+
+/// The first line of a document that contains one of the terms.
+fn snippet(document: &Document, terms: &[String]) -> Option<Snippet> {
+    if terms.is_empty() {
+        return None;
+    }
+
+    document.text.lines().enumerate().find_map(|(i, line)| {
+        let line = line.trim();
+        let lower = line.to_ascii_lowercase();
+        let position = terms.iter().filter_map(|t| lower.find(t.as_str())).min()?;
+        Some(Snippet {
+            source: document.source,
+            line: i + 1,
+            text: highlight(&excerpt(line, position), terms),
+        })
+    })
+}
+
+/// Cut a long line to a window around the byte position of a match.
+fn excerpt(line: &str, position: usize) -> String {
+    if line.chars().count() <= SNIPPET_WIDTH {
+        return line.to_string();
+    }
+
+    let floor = |mut i: usize| {
+        while !line.is_char_boundary(i) {
+            i -= 1;
+        }
+        i
+    };
+
+    let start = floor(position.saturating_sub(SNIPPET_WIDTH / 3).min(line.len()));
+    let end = floor((start + SNIPPET_WIDTH).min(line.len()));
+
+    let mut result = String::new();
+    if start > 0 {
+        result.push('…');
+    }
+    result.push_str(line[start..end].trim());
+    if end < line.len() {
+        result.push('…');
+    }
+    result
+}
+
+/// Show all occurrences of the terms in bold.
+fn highlight(text: &str, terms: &[String]) -> String {
+    let lower = text.to_ascii_lowercase();
+    let mut marked = vec![false; text.len()];
+
+    for term in terms.iter().filter(|t| !t.is_empty()) {
+        for (i, _) in lower.match_indices(term.as_str()) {
+            marked[i..i + term.len()].fill(true);
+        }
+    }
+
+    let mut result = String::with_capacity(text.len());
+    let mut bold = false;
+
+    for (i, c) in text.char_indices() {
+        if marked[i] != bold {
+            bold = marked[i];
+            result.push_str(if bold { ANSI_BOLD } else { ANSI_RESET });
+        }
+        result.push(c);
+    }
+
+    if bold {
+        result.push_str(ANSI_RESET);
+    }
+
+    result
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{excerpt, highlight, Index, Source};
+    use crate::day;
+    use crate::template::{strip_ansi, tags::Tags, ANSI_BOLD, ANSI_RESET};
+
+    fn terms(s: &str) -> Vec<String> {
+        s.split_whitespace().map(str::to_string).collect()
+    }
+
+    fn index() -> Index {
+        let mut index = Index {
+            tags: Tags::parse("05 ranges\n10 grid geometry\n"),
+            ..Index::default()
+        };
+        index.add(
+            day!(5),
+            Source::Puzzle,
+            "--- Day 5 ---\nThe almanac lists seed ranges.\nEach seed needs soil.".into(),
+        );
+        index.add(
+            day!(10),
+            Source::Puzzle,
+            "A field of pipes. Find the loop.".into(),
+        );
+        index.add(
+            day!(10),
+            Source::Solution,
+            "fn part_one() {\n    // walk the loop of pipes\n}".into(),
+        );
+        index
+    }
+
+    #[test]
+    fn ranks_matching_days() {
+        let index = index();
+
+        let hits = index.search(&terms("LOOP"), &[]);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].day, day!(10));
+        assert_eq!(hits[0].score, 2);
+        assert_eq!(hits[0].snippets[1].source, Source::Solution);
+        assert_eq!(hits[0].snippets[1].line, 2);
+        assert_eq!(
+            strip_ansi(&hits[0].snippets[1].text),
+            "// walk the loop of pipes"
+        );
+
+        // all terms must match, tags count as matches.
+        assert!(index.search(&terms("seed pipes"), &[]).is_empty());
+        let hits = index.search(&terms("seed ranges"), &[]);
+        assert_eq!(hits[0].day, day!(5));
+        assert_eq!(hits[0].score, 4);
+    }
+
+    #[test]
+    fn filters_by_tags() {
+        let index = index();
+
+        let hits = index.search(&[], &["grid".into()]);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].day, day!(10));
+        assert!(hits[0].snippets.is_empty());
+
+        assert!(index.search(&terms("seed"), &["grid".into()]).is_empty());
+    }
+
+    #[test]
+    fn formats_snippets() {
+        assert_eq!(
+            highlight("Seed to soil", &terms("seed soil")),
+            format!("{ANSI_BOLD}Seed{ANSI_RESET} to {ANSI_BOLD}soil{ANSI_RESET}")
+        );
+
+        let line = "word ".repeat(40) + "needle" + &" word".repeat(40);
+        let cut = excerpt(&line, line.find("needle").unwrap());
+        assert!(cut.starts_with('…') && cut.ends_with('…'));
+        assert!(cut.contains("needle"));
+        assert!(cut.chars().count() <= 82);
+    }
+}
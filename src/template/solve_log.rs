@@ -0,0 +1,266 @@
+/// Append-only log of when puzzles were opened and answers were submitted.
+/// `download` records the open time, `--submit` records each verdict. The `stats` command derives solve times from it.
+use std::{
+    fmt::{self, Display},
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::template::{aoc_cli::SubmitVerdict, config};
+use crate::{all_days, Day};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Event {
+    Opened,
+    Submitted { part: u8, correct: bool },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Entry {
+    /// Seconds since the unix epoch.
+    pub time: u64,
+    pub day: Day,
+    pub event: Event,
+}
+
+impl Entry {
+    /// An entry for an event that happened just now.
+    #[must_use]
+    pub fn now(day: Day, event: Event) -> Self {
+        let time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        Self { time, day, event }
+    }
+
+    /// The entry for a submission, if the verdict says whether the answer was right.
+    #[must_use]
+    pub fn from_verdict(day: Day, part: u8, verdict: SubmitVerdict) -> Option<Self> {
+        let correct = match verdict {
+            SubmitVerdict::Correct => true,
+            SubmitVerdict::Incorrect => false,
+            _ => return None,
+        };
+        Some(Self::now(day, Event::Submitted { part, correct }))
+    }
+
+    /// Each line holds `<time> <day> opened` or `<time> <day> <part> correct|wrong`.
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.split_whitespace();
+        let time = fields.next()?.parse().ok()?;
+        let day = fields.next()?.parse().ok()?;
+
+        let event = match (fields.next()?, fields.next()) {
+            ("opened", None) => Event::Opened,
+            (part, Some(verdict)) => Event::Submitted {
+                part: part.parse().ok().filter(|p| *p == 1 || *p == 2)?,
+                correct: match verdict {
+                    "correct" => true,
+                    "wrong" => false,
+                    _ => return None,
+                },
+            },
+            _ => return None,
+        };
+
+        Some(Self { time, day, event })
+    }
+}
+
+impl Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.event {
+            Event::Opened => write!(f, "{} {} opened", self.time, self.day),
+            Event::Submitted { part, correct } => {
+                let verdict = if correct { "correct" } else { "wrong" };
+                write!(f, "{} {} {part} {verdict}", self.time, self.day)
+            }
+        }
+    }
+}
+
+/// Append an entry to the log file.
+pub fn append(entry: Entry) -> Result<(), io::Error> {
+    append_to(&config::get().paths.solve_log, entry)
+}
+
+/// Append an entry to the log file at `path`.
+pub fn append_to(path: &Path, entry: Entry) -> Result<(), io::Error> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{entry}")
+}
+
+/// Solve times of a day, derived from the log.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DayStats {
+    /// Time from opening the puzzle to solving part one.
+    pub part_1: Option<Duration>,
+    /// Time from solving part one to solving part two.
+    pub part_2: Option<Duration>,
+    /// Wrong answers submitted before each part was solved.
+    pub wrong_attempts: u32,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct SolveLog {
+    entries: Vec<Entry>,
+}
+
+impl SolveLog {
+    pub fn load() -> Result<Self, io::Error> {
+        match fs::read_to_string(&config::get().paths.solve_log) {
+            Ok(s) => Ok(Self::parse(&s)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Lines that can't be parsed are skipped.
+    pub(crate) fn parse(s: &str) -> Self {
+        Self {
+            entries: s.lines().filter_map(Entry::parse).collect(),
+        }
+    }
+
+    /// Stats of a day, `None` if nothing was logged for it.
+    #[must_use]
+    pub fn stats(&self, day: Day) -> Option<DayStats> {
+        let mut entries: Vec<&Entry> = self.entries.iter().filter(|e| e.day == day).collect();
+        if entries.is_empty() {
+            return None;
+        }
+        entries.sort_by_key(|e| e.time);
+
+        let opened = entries
+            .iter()
+            .find(|e| e.event == Event::Opened)
+            .map(|e| e.time);
+        let solved = |part: u8| {
+            entries
+                .iter()
+                .find(|e| {
+                    e.event
+                        == Event::Submitted {
+                            part,
+                            correct: true,
+                        }
+                })
+                .map(|e| e.time)
+        };
+        let (solved_1, solved_2) = (solved(1), solved(2));
+
+        // answers submitted after a part was solved don't count, e.g. when re-running `--submit`.
+        let wrong_attempts = entries
+            .iter()
+            .filter(|e| match e.event {
+                Event::Submitted {
+                    part,
+                    correct: false,
+                } => {
+                    let solved = if part == 1 { solved_1 } else { solved_2 };
+                    solved.is_none_or(|t| e.time <= t)
+                }
+                _ => false,
+            })
+            .count();
+
+        let between = |start: Option<u64>, end: Option<u64>| {
+            Some(Duration::from_secs(end?.checked_sub(start?)?))
+        };
+
+        Some(DayStats {
+            part_1: between(opened, solved_1),
+            part_2: between(solved_1, solved_2),
+            wrong_attempts: u32::try_from(wrong_attempts).unwrap_or(u32::MAX),
+        })
+    }
+
+    /// Stats of all days that have log entries.
+    #[must_use]
+    pub fn all_stats(&self) -> Vec<(Day, DayStats)> {
+        all_days()
+            .filter_map(|day| Some((day, self.stats(day)?)))
+            .collect()
+    }
+}
+
+/// Format a solve time as `h:mm:ss`, prefixed with the number of days if it took longer.
+#[must_use]
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    let (days, secs) = (secs / 86_400, secs % 86_400);
+    let clock = format!("{}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60);
+
+    if days > 0 {
+        format!("{days}d {clock}")
+    } else {
+        clock
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{format_duration, DayStats, Entry, Event, SolveLog};
+    use crate::day;
+
+    const LOG: &str = "\
+1000 01 opened
+1600 01 1 wrong
+1700 01 1 correct
+2000 01 opened
+2700 01 2 correct
+3000 02 opened
+3100 02 1 wrong
+3200 02 1 wrong
+not an entry
+";
+
+    #[test]
+    fn derives_stats() {
+        let log = SolveLog::parse(LOG);
+
+        assert_eq!(
+            log.stats(day!(1)),
+            Some(DayStats {
+                part_1: Some(Duration::from_secs(700)),
+                part_2: Some(Duration::from_secs(1000)),
+                wrong_attempts: 1,
+            })
+        );
+        assert_eq!(
+            log.stats(day!(2)),
+            Some(DayStats {
+                part_1: None,
+                part_2: None,
+                wrong_attempts: 2,
+            })
+        );
+        assert_eq!(log.stats(day!(3)), None);
+        assert_eq!(log.all_stats().len(), 2);
+    }
+
+    #[test]
+    fn roundtrips_entries() {
+        let entry = Entry {
+            time: 42,
+            day: day!(7),
+            event: Event::Submitted {
+                part: 2,
+                correct: false,
+            },
+        };
+        assert_eq!(entry.to_string(), "42 07 2 wrong");
+        assert_eq!(Entry::parse(&entry.to_string()), Some(entry));
+        assert_eq!(Entry::parse("42 07 3 wrong"), None);
+    }
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(format_duration(Duration::from_secs(754)), "0:12:34");
+        assert_eq!(format_duration(Duration::from_secs(90_061)), "1d 1:01:01");
+    }
+}
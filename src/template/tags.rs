@@ -0,0 +1,79 @@
+/// Topic tags per day, e.g. `grid` or `dp`, used to search and filter the solutions.
+/// Tags are maintained by hand in `data/tags.txt`, see `paths.tags` in the config.
+use std::{collections::BTreeMap, fs, io};
+
+use crate::template::config;
+use crate::Day;
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Tags {
+    days: BTreeMap<Day, Vec<String>>,
+}
+
+impl Tags {
+    pub fn load() -> Result<Self, io::Error> {
+        match fs::read_to_string(&config::get().paths.tags) {
+            Ok(s) => Ok(Self::parse(&s)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Each line holds `<day> <tag>...`, tags are lowercased. Comments start with `#`.
+    pub(crate) fn parse(s: &str) -> Self {
+        let days = s
+            .lines()
+            .filter_map(|line| {
+                let line = line.split('#').next()?;
+                let mut fields = line.split_whitespace();
+                let day = fields.next()?.parse().ok()?;
+                let tags = fields.map(str::to_lowercase).collect();
+                Some((day, tags))
+            })
+            .collect();
+
+        Self { days }
+    }
+
+    #[must_use]
+    pub fn get(&self, day: Day) -> &[String] {
+        self.days.get(&day).map_or(&[], Vec::as_slice)
+    }
+
+    /// Whether a day has all of the given tags. Matches every day if no tags are given.
+    #[must_use]
+    pub fn matches(&self, day: Day, filter: &[String]) -> bool {
+        let tags = self.get(day);
+        filter
+            .iter()
+            .all(|wanted| tags.iter().any(|tag| tag.eq_ignore_ascii_case(wanted)))
+    }
+}
+
+/// Split a comma separated list of tags as passed to `--tag`.
+#[must_use]
+pub fn parse_filter(s: &str) -> Vec<String> {
+    s.split(',')
+        .map(|tag| tag.trim().to_lowercase())
+        .filter(|tag| !tag.is_empty())
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_filter, Tags};
+    use crate::day;
+
+    #[test]
+    fn parses_and_filters_tags() {
+        let tags = Tags::parse("# day tags\n03 grid\n12 DP memo\nnot a day\n");
+
+        assert_eq!(tags.get(day!(3)), ["grid"]);
+        assert_eq!(tags.get(day!(12)), ["dp", "memo"]);
+        assert!(tags.get(day!(4)).is_empty());
+
+        assert!(tags.matches(day!(12), &parse_filter("dp, memo")));
+        assert!(!tags.matches(day!(12), &parse_filter("dp,grid")));
+        assert!(tags.matches(day!(4), &parse_filter("")));
+    }
+}
@@ -0,0 +1,98 @@
+use mygrid::{
+    direction::{Direction, DOWN, LEFT, ORTHOGONAL, RIGHT, UP},
+    point::Point,
+    polygon::Polygon,
+    sparse_grid::SparseGrid,
+};
+
+/// The day of this solution, used to read its example files.
+pub const DAY: crate::Day = crate::day!(18);
+
+fn parse_part_one(input: &str) -> Vec<(Direction, i64)> {
+    input
+        .lines()
+        .map(|line| {
+            let mut parts = line.split_whitespace();
+            let dir: Direction = parts.next().unwrap().into();
+            let dist: i64 = parts.next().unwrap().parse().unwrap();
+            (dir, dist)
+        })
+        .collect::<Vec<_>>()
+}
+
+pub fn part_one(input: &str) -> Option<i64> {
+    // parse instructions
+    let instructions = parse_part_one(input);
+
+    // transform to grid
+    let mut trench = SparseGrid::new();
+    let mut pos = Point::new(0, 0);
+    trench.insert(pos, b'#');
+    for (dir, dist) in instructions {
+        for _ in 0..dist {
+            pos = pos + dir;
+            trench.insert(pos, b'#');
+        }
+    }
+    // keep a border of ground around the trench to fill the exterior from the corner
+    let (grid, _) = trench.to_grid_with_margin(b'.', 1);
+    //println!("{}", grid.to_fmt(|_, c| format!("{}", *c as char)));
+
+    // everything not reached from outside is trench or dug interior
+    let outside = grid.flood_fill(Point::new(0, 0), |&c| c == b'.', &ORTHOGONAL);
+    Some((grid.iter().count() - outside.len()) as i64)
+}
+
+fn dig_area(instructions: &[(Direction, i64)]) -> i64 {
+    Polygon::from_moves(Point::new(0, 0), instructions.iter().copied()).lattice_points() as i64
+}
+
+pub fn part_one_shoelace(input: &str) -> Option<i64> {
+    Some(dig_area(&parse_part_one(input)))
+}
+
+pub fn part_two(input: &str) -> Option<i64> {
+    // parse instructions
+    let instructions = input
+        .lines()
+        .map(|line| {
+            let mut parts = line.split_whitespace();
+            let rgb = parts.nth(2).unwrap();
+            let dist = i64::from_str_radix(&rgb[2..rgb.len() - 2], 16).unwrap();
+            let dir = match rgb.chars().nth(7).unwrap() {
+                '0' => RIGHT,
+                '1' => DOWN,
+                '2' => LEFT,
+                '3' => UP,
+                _ => unreachable!(),
+            };
+            (dir, dist)
+        })
+        .collect::<Vec<_>>();
+    //println!("{:?}", instructions);
+
+    Some(dig_area(&instructions))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY, 1));
+        assert_eq!(result, Some(62));
+    }
+
+    #[test]
+    fn test_part_one_shoelace() {
+        let result = part_one_shoelace(&crate::template::read_file("examples", DAY, 1));
+        assert_eq!(result, Some(62));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", DAY, 1));
+        assert_eq!(result, Some(952408144115));
+    }
+}
@@ -0,0 +1,238 @@
+use std::collections::HashSet;
+
+use mygrid::{
+    cuboid::Cuboid,
+    grid3::Grid3,
+    point3::{Point3, UP_3D},
+};
+
+/// The day of this solution, used to read its example files.
+pub const DAY: crate::Day = crate::day!(22);
+
+// cell occupancy, the index of the block in each cell
+type Cells = Grid3<Option<usize>>;
+
+#[inline]
+fn parse_point(s: &str) -> Point3 {
+    let n = s
+        .split(',')
+        .map(|n| n.trim().parse::<i64>().unwrap())
+        .collect::<Vec<_>>();
+    Point3::new(n[0], n[1], n[2])
+}
+
+#[inline]
+fn parse_line(s: &str) -> Cuboid {
+    let (a, b) = s.split_once("~").unwrap();
+    Cuboid::new(parse_point(a), parse_point(b))
+}
+
+#[inline]
+pub fn parse(input: &str) -> Vec<Cuboid> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(parse_line)
+        .collect::<Vec<_>>()
+}
+
+#[inline]
+fn build_cells(blocks: &[Cuboid]) -> Cells {
+    let max = blocks
+        .iter()
+        .fold(Point3::default(), |max, block| max.max(&block.max));
+    let mut cells = Grid3::new_to_max_point(max, None);
+    for (id, block) in blocks.iter().enumerate() {
+        cells.fill(block, Some(id));
+    }
+    cells
+}
+
+// the other blocks in the layer of cells just below (or above) the block
+#[inline]
+fn blocks_in_layer(id: usize, layer: Cuboid, cells: &Cells) -> HashSet<usize> {
+    layer
+        .points()
+        .filter_map(|p| cells.get(p).copied().flatten())
+        .filter(|&other| other != id)
+        .collect()
+}
+
+#[inline]
+fn blocks_below(id: usize, block: &Cuboid, cells: &Cells) -> HashSet<usize> {
+    let bottom = Cuboid::new(
+        block.min,
+        Point3::new(block.max.x, block.max.y, block.min.z),
+    );
+    blocks_in_layer(id, bottom.drop(1), cells)
+}
+
+#[inline]
+fn blocks_above(id: usize, block: &Cuboid, cells: &Cells) -> HashSet<usize> {
+    let top = Cuboid::new(
+        Point3::new(block.min.x, block.min.y, block.max.z),
+        block.max,
+    );
+    blocks_in_layer(id, top.translate(UP_3D), cells)
+}
+
+#[inline]
+fn has_hit_floor(block: &Cuboid) -> bool {
+    block.min.z == 1
+}
+
+#[inline]
+fn can_fall_down_one(id: usize, block: &Cuboid, cells: &Cells) -> bool {
+    blocks_below(id, block, cells).is_empty()
+}
+
+#[inline]
+fn tree_fall_down(blocks: &mut [Cuboid], cells: &mut Cells) -> u32 {
+    // move one down until we hit the floor or another block
+    let mut moved = true;
+    let mut moved_blocks = HashSet::new();
+    while moved {
+        moved = false;
+        for (id, block) in blocks.iter_mut().enumerate() {
+            if has_hit_floor(block) {
+                continue;
+            }
+
+            if !can_fall_down_one(id, block, cells) {
+                continue;
+            }
+
+            cells.fill(block, None);
+            *block = block.drop(1);
+            cells.fill(block, Some(id));
+
+            moved_blocks.insert(id);
+
+            moved = true;
+        }
+    }
+    moved_blocks.len() as u32
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let mut blocks = parse(input);
+    let mut cells = build_cells(&blocks);
+
+    tree_fall_down(&mut blocks, &mut cells);
+
+    let mut valid_desintegrate = 0;
+    for (id, block) in blocks.iter().enumerate() {
+        let blocks_resting_on = blocks_above(id, block, &cells);
+
+        // only safe if every block resting on it has another support
+        let can_desintegrate = blocks_resting_on
+            .iter()
+            .all(|&resting| blocks_below(resting, &blocks[resting], &cells).len() > 1);
+
+        if can_desintegrate {
+            valid_desintegrate += 1;
+        }
+    }
+
+    Some(valid_desintegrate)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let mut blocks = parse(input);
+    let mut cells = build_cells(&blocks);
+
+    tree_fall_down(&mut blocks, &mut cells);
+
+    let mut total_moving = 0;
+    for id in 0..blocks.len() {
+        let mut blocks_copy = blocks.clone();
+        blocks_copy.remove(id);
+        let mut cells_copy = build_cells(&blocks_copy);
+
+        total_moving += tree_fall_down(&mut blocks_copy, &mut cells_copy);
+    }
+
+    Some(total_moving)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY, 1));
+        assert_eq!(result, Some(5));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", DAY, 1));
+        assert_eq!(result, Some(7));
+    }
+
+    #[test]
+    fn test_blocks_below() {
+        let input = "
+1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9
+        ";
+        let blocks = parse(input);
+        let cells = build_cells(&blocks);
+        let (a, b, c, e) = (0, 1, 2, 4);
+
+        let test_cases = vec![
+            (6, vec![]),
+            (5, vec![e]),
+            (4, vec![]),
+            (3, vec![c]),
+            (2, vec![]),
+            (1, vec![a]),
+            (0, vec![]),
+        ];
+        for (test, expected) in test_cases {
+            let below = blocks_below(test, &blocks[test], &cells);
+            assert_eq!(below, expected.into_iter().collect::<HashSet<_>>());
+        }
+        assert_eq!(
+            blocks_above(a, &blocks[a], &cells),
+            [b].into_iter().collect::<HashSet<_>>()
+        );
+    }
+
+    #[test]
+    fn test_tree_fall_down() {
+        let input = "
+1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9
+    ";
+        let mut blocks = parse(input);
+        let mut cells = build_cells(&blocks);
+
+        tree_fall_down(&mut blocks, &mut cells);
+
+        let (a, b, c, d, e, f, g) = (
+            blocks[0], blocks[1], blocks[2], blocks[3], blocks[4], blocks[5], blocks[6],
+        );
+
+        let corners = |block: Cuboid| (block.min, block.max);
+        assert_eq!(corners(a), (Point3::new(1, 0, 1), Point3::new(1, 2, 1)));
+        assert_eq!(corners(b), (Point3::new(0, 0, 2), Point3::new(2, 0, 2)));
+        assert_eq!(corners(c), (Point3::new(0, 2, 2), Point3::new(2, 2, 2)));
+        assert_eq!(corners(d), (Point3::new(0, 0, 3), Point3::new(0, 2, 3)));
+        assert_eq!(corners(e), (Point3::new(2, 0, 3), Point3::new(2, 2, 3)));
+        assert_eq!(corners(f), (Point3::new(0, 1, 4), Point3::new(2, 1, 4)));
+        assert_eq!(corners(g), (Point3::new(1, 1, 5), Point3::new(1, 1, 6)));
+    }
+}